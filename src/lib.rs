@@ -2,7 +2,31 @@
 //!
 //! e.g.:
 //! - chaining
+//! - chaining/composing with `From`/`TryFrom` conversions between stages
+//! - chaining while keeping intermediate results
+//! - chaining with access to the original input
+//! - chaining/composing functions returning borrows (accessors, projections)
 //! - composing
+//! - chaining one-to-many functions (`flat_map`)
+//! - chaining/composing fallible functions (short-circuiting like `?`)
+//! - lifting functions into `Option`s/`Result`s/iterables
+//! - validating with many checks at once (accumulating all errors)
+//! - memoizing (with unbounded, LRU or thread-safe caches)
+//! - recursive closures (fixed-point combinator, optionally memoized)
+//! - stack-safe recursion (trampolines)
+//! - iterating functions (`fⁿ`, until predicate, until fixed point)
+//! - lazy values (thunks evaluated once)
+//! - choosing between functions (`f ||| g`, `f +++ g`)
+//! - conditional application (`when`, `unless`, `if_else`, `guard`, `cond!`)
+//! - partial functions (`is_defined_at`, `or_else`) and fallbacks
+//! - identity, constant and projection function objects
+//! - comparators (`reversed(then_comparing(comparing(key), key2))`)
+//! - comparing/hashing values by a projected key (`ByKey`)
+//! - combining predicates (`not(and(p, q))`, `all_of((p, q, r))`)
+//! - combinatory logic (`B`, `C`, `K`, `I`, `S`, `W`, `Φ`, `Ψ`)
+//! - adapting arity (ignoring, duplicating or dropping arguments)
+//! - adapting how argument is passed (by reference, cloned, `Deref`, `AsRef`)
+//! - mapping each argument with its own function (`useWith`)
 //! - applying to values
 //! - supplying arguments
 //! - currying (O_O)
@@ -92,27 +116,175 @@
 mod local_macros;
 /// Definitions on public (`#[macro_export]`) macros
 mod macro_def;
-/// 'Sealed' trait that prevents implementing tuple traits in other crates
+/// 'Sealed' trait that prevents implementing tuple and `fallible` traits in
+/// other crates
 mod sealed;
 
 /// Helper module for moving stable thing to dedicated dir
 mod stable {
+    /// Adapters for arity mismatches (ignoring and duplicating arguments)
+    pub mod args;
+    /// Identity, constant and projection function objects
+    pub mod basic;
+    /// Adapters between owned and borrowed arguments
+    pub mod borrow;
     pub mod chain;
+    /// Chaining one-to-many functions
+    pub mod chain_flat;
+    /// Chaining functions with `Into`/`TryFrom` conversion in between
+    pub mod chain_into;
+    /// Chaining functions, keeping intermediate results
+    pub mod chain_keep;
+    /// Chaining functions which return borrows of their arguments
+    pub mod chain_ref;
+    /// Chaining functions, passing the original input to the second one
+    pub mod chain_with_input;
+    /// Functions on `Either`-like sum types
+    pub mod choice;
+    /// Comparators built from key-extraction functions
+    pub mod cmp;
+    /// Classic combinators of [combinatory logic]
+    ///
+    /// | Combinator | Definition                  | Alias        |
+    /// |------------|-----------------------------|--------------|
+    /// | [`b`]      | `B f g x = f (g x)`         | [`compose`]  |
+    /// | [`c`]      | `C f x y = f y x`           | [`flip`]     |
+    /// | [`k`]      | `K x y = x`                 | [`constant`] |
+    /// | [`i`]      | `I x = x`                   | [`Id`]       |
+    /// | [`s`]      | `S f g x = f x (g x)`       |              |
+    /// | [`w`]      | `W f x = f x x`             | [`dup`]      |
+    /// | [`phi`]    | `Φ f g h x = f (g x) (h x)` | fork         |
+    /// | [`psi`]    | `Ψ f g x y = f (g x) (g y)` | `on`         |
+    ///
+    /// Functions of this module accept/return functions of 1 or 2 arguments,
+    /// for versions which work with functions of any arity see
+    /// [`unstable::combinators`].
+    ///
+    /// [combinatory logic]: https://en.wikipedia.org/wiki/Combinatory_logic
+    /// [`b`]: crate::combinators::b
+    /// [`c`]: crate::combinators::c
+    /// [`k`]: crate::combinators::k
+    /// [`i`]: crate::combinators::i
+    /// [`s`]: crate::combinators::s
+    /// [`w`]: crate::combinators::w
+    /// [`phi`]: crate::combinators::phi
+    /// [`psi`]: crate::combinators::psi
+    /// [`compose`]: crate::compose
+    /// [`flip`]: crate::flip
+    /// [`constant`]: crate::constant
+    /// [`Id`]: crate::Id
+    /// [`dup`]: crate::dup
+    /// [`unstable::combinators`]: crate::unstable::combinators
+    pub mod combinators;
     pub mod compose;
+    /// Conditional application of functions
+    pub mod cond;
+    /// `?`-like short-circuiting for `Result` and `Option`
+    pub mod fallible;
+    /// Fixed-point combinators for recursive closures
+    pub mod fix;
     pub mod flip;
+    /// Repeated application of functions
+    pub mod iterate;
+    /// Values computed on first access (thunks)
+    pub mod lazy;
+    /// Lifting functions into `Option`s, `Result`s and iterables
+    pub mod lift;
+    /// Mapping arguments of functions
+    pub mod map_args;
+    /// Memoization of functions with pluggable caches
+    pub mod memo;
+    /// Partial functions, i.e. functions defined only for some of their inputs
+    /// (like Scala's [`PartialFunction`])
+    ///
+    /// [`PartialFunction`]: https://www.scala-lang.org/api/current/scala/PartialFunction.html
+    pub mod partial;
+    /// Combinators for predicates (`Fn(&T) -> bool`)
+    pub mod predicate;
     pub mod product;
+    /// Stack-safe recursion via trampolining
+    pub mod trampoline;
+    /// Chaining fallible functions
+    pub mod try_chain;
+    /// Composing fallible functions
+    pub mod try_compose;
     /// Unit function output.
     pub mod unit;
+    /// Running tuples of checks, accumulating all errors
+    pub mod validation;
     /// Extensions for all types
     pub mod value;
 }
 
 pub use stable::{
+    args::{dup, dup_mut, dup_once, ignore_args, ignore_args_mut, ignore_args_once},
+    basic::{constant, nth_arg, Const, Id, Proj},
+    borrow::{
+        as_ref_arg, as_ref_arg_mut, as_ref_arg_once, by_ref, by_ref_mut, by_ref_once, cloned,
+        cloned_mut, cloned_once, deref_arg, deref_arg_mut, deref_arg_once,
+    },
     chain::{chain, chain_mut, chain_once},
+    chain_flat::{chain_flat, chain_flat_mut, chain_flat_once},
+    chain_into::{
+        chain_into, chain_into_mut, chain_into_once, compose_into, compose_into_mut,
+        compose_into_once, try_chain_into, try_chain_into_mut, try_chain_into_once,
+    },
+    chain_keep::{
+        chain_keep, chain_keep_mut, chain_keep_once, chain_keep_push, chain_keep_push_mut,
+        chain_keep_push_once,
+    },
+    chain_ref::{
+        chain_proj, chain_proj_mut, chain_proj_once, chain_ref, chain_ref_mut, chain_ref_once,
+        compose_proj, compose_proj_mut, compose_proj_once, compose_ref, compose_ref_mut,
+        compose_ref_once,
+    },
+    chain_with_input::{
+        chain_with_input, chain_with_input_mut, chain_with_input_once, chain_with_input_owned,
+        chain_with_input_owned_mut, chain_with_input_owned_once,
+    },
+    choice::{
+        bimap, bimap_mut, bimap_once, choice, choice_mut, choice_once, left, left_mut, left_once,
+        right, right_mut, right_once,
+    },
+    cmp, combinators,
     compose::{compose, compose_mut, compose_once},
-    flip::{flip, flip_mut, flip_once},
+    cond::{
+        guard, guard_mut, guard_once, if_else, if_else_mut, if_else_once, unless, unless_mut,
+        unless_once, when, when_mut, when_once,
+    },
+    fallible,
+    fix::{fix, memo_fix},
+    flip::{
+        flip, flip_both, flip_both_mut, flip_both_once, flip_mut, flip_once, flip_output,
+        flip_output_mut, flip_output_once,
+    },
+    iterate::{
+        fixpoint, fixpoint_mut, iterate, iterate_mut, iterate_until, iterate_until_mut, orbit,
+        Orbit,
+    },
+    lazy,
+    lift::{
+        lift_option, lift_option2, lift_option2_mut, lift_option2_once, lift_option3,
+        lift_option3_mut, lift_option3_once, lift_option4, lift_option4_mut, lift_option4_once,
+        lift_option_mut, lift_option_once, lift_result, lift_result2, lift_result2_mut,
+        lift_result2_once, lift_result3, lift_result3_mut, lift_result3_once, lift_result4,
+        lift_result4_mut, lift_result4_once, lift_result_mut, lift_result_once, lift_vec,
+        lift_vec2, lift_vec2_mut, lift_vec3, lift_vec3_mut, lift_vec4, lift_vec4_mut, lift_vec_mut,
+    },
+    map_args::{
+        map_args2, map_args2_mut, map_args2_once, map_args3, map_args3_mut, map_args3_once,
+        map_args4, map_args4_mut, map_args4_once, map_args5, map_args5_mut, map_args5_once,
+        map_args6, map_args6_mut, map_args6_once,
+    },
+    memo::{self, memoize, memoize_lru, memoize_sync},
+    partial::{self, fallback, fallback_mut, fallback_once},
+    predicate,
     product::{product, product_mut, product_once},
+    trampoline::{trampoline, trampoline_fix, trampoline_mut, Bounce, Trampoline},
+    try_chain::{try_chain, try_chain_mut, try_chain_once},
+    try_compose::{try_compose, try_compose_mut, try_compose_once},
     unit::{unit, unit_mut, unit_once},
+    validation::{self, validate},
     value,
 };
 
@@ -120,23 +292,95 @@ pub use stable::{
 #[cfg(feature = "nightly")]
 pub mod unstable {
     pub use self::{
+        args::{dup, ignore_args, take_args, Dup, IgnoreArgs, TakeArgs},
         chain::{chain, Chain},
+        chain_flat::{chain_flat, ChainFlat},
+        chain_into::{chain_into, compose_into, try_chain_into, ChainInto, TryChainInto},
+        chain_ref::{chain_proj, chain_ref, compose_proj, compose_ref, ChainRef},
+        chain_with_input::{
+            chain_with_input, chain_with_input_owned, ChainWithInput, ChainWithInputOwned,
+        },
+        choice::{bimap, choice, left, right, Bimap, Choice, Left, Right},
         compose::{compose, Compose},
         curry::{curry, Curry},
         ext::FnExt,
-        flip::{flip, Flip},
+        fix::{fix, memo_fix, Fix, MemoFix},
+        flip::{flip, flip_both, flip_output, Flip, FlipBoth, FlipOutput},
+        lift::{lift_option, lift_result, lift_vec, LiftOption, LiftResult, LiftVec},
+        map_args::{map_args, MapArgs},
+        memo::memoize,
+        power::{power, Power},
+        predicate::{all_of, and, any_of, not, or, xor, AllOf, And, AnyOf, Not, Or, Xor},
         supply::{supply, Supply},
+        try_chain::{try_chain, TryChain},
+        try_compose::{try_compose, TryCompose},
         unit::{unit, Unit},
         untuple::{untuple, Untuple},
         value::ValueExtUnstable,
     };
 
+    mod args;
+    mod basic;
     mod chain;
+    mod chain_flat;
+    mod chain_into;
+    mod chain_ref;
+    mod chain_with_input;
+    mod choice;
+    /// Classic combinators of [combinatory logic] as nameable function objects
+    ///
+    /// | Combinator | Definition                  | Type                         |
+    /// |------------|-----------------------------|------------------------------|
+    /// | [`b`]      | `B f g x = f (g x)`         | [`B`] (alias to [`Compose`]) |
+    /// | [`c`]      | `C f x y = f y x`           | [`C`] (alias to [`Flip`])    |
+    /// | [`k`]      | `K x y = x`                 | [`K`] (alias to [`Const`])   |
+    /// | [`i`]      | `I x = x`                   | [`I`] (alias to [`Id`])      |
+    /// | [`s`]      | `S f g x = f x (g x)`       | [`S`]                        |
+    /// | [`w`]      | `W f x = f x x`             | [`W`] (alias to [`Dup`])     |
+    /// | [`phi`]    | `Φ f g h x = f (g x) (h x)` | [`Phi`]                      |
+    /// | [`psi`]    | `Ψ f g x y = f (g x) (g y)` | [`Psi`]                      |
+    ///
+    /// Unlike [stable versions] these work with functions of many arguments
+    /// where it makes sense (e.g. `x` in `B`, `C`, `K`, `S` and `Φ` can be many
+    /// arguments).
+    ///
+    /// [combinatory logic]: https://en.wikipedia.org/wiki/Combinatory_logic
+    /// [`b`]: crate::unstable::combinators::b
+    /// [`c`]: crate::unstable::combinators::c
+    /// [`k`]: crate::unstable::combinators::k
+    /// [`i`]: crate::unstable::combinators::i
+    /// [`s`]: crate::unstable::combinators::s
+    /// [`w`]: crate::unstable::combinators::w
+    /// [`phi`]: crate::unstable::combinators::phi
+    /// [`psi`]: crate::unstable::combinators::psi
+    /// [`B`]: crate::unstable::combinators::B
+    /// [`C`]: crate::unstable::combinators::C
+    /// [`K`]: crate::unstable::combinators::K
+    /// [`I`]: crate::unstable::combinators::I
+    /// [`S`]: crate::unstable::combinators::S
+    /// [`W`]: crate::unstable::combinators::W
+    /// [`Phi`]: crate::unstable::combinators::Phi
+    /// [`Psi`]: crate::unstable::combinators::Psi
+    /// [`Compose`]: crate::unstable::Compose
+    /// [`Flip`]: crate::unstable::Flip
+    /// [`Const`]: crate::Const
+    /// [`Id`]: crate::Id
+    /// [`Dup`]: crate::unstable::Dup
+    /// [stable versions]: crate::combinators
+    pub mod combinators;
     mod compose;
     mod curry;
     mod ext;
+    mod fix;
     mod flip;
+    mod lift;
+    mod map_args;
+    mod memo;
+    mod power;
+    mod predicate;
     mod supply;
+    mod try_chain;
+    mod try_compose;
     mod unit;
     mod untuple;
     mod value;
@@ -153,10 +397,16 @@ pub mod unstable {
 /// [Draft RFC: variadic generics]: https://github.com/rust-lang/rfcs/issues/376
 /// [stdlib]: https://doc.rust-lang.org/std/primitive.tuple.html#trait-implementations
 pub mod tuple {
+    /// Transpose tuple of results accumulating all errors (`(Result<A, E>,
+    /// Result<B, E>) => Result<(A, B), Vec<E>>`)
+    pub mod accumulate;
     /// Append element to tuple (`T + (A, B) => (T, A, B)`)
     pub mod append;
     /// Tuple with at least 2 elements.
     pub mod at_least_2;
+    /// Cartesian product of tuple of iterables (`(Vec<A>, Vec<B>) => Vec<(A,
+    /// B)>`)
+    pub mod cartesian;
     /// Concat tuples (`(A, B) + (C, D) => (A, B, C, D)`)
     ///
     /// **NOTE**: this module is under `#[cfg(feature = "concat")]`
@@ -164,10 +414,20 @@ pub mod tuple {
     pub mod concat;
     /// Flip tuple (`(A, B) => (B, A)`)
     pub mod flip;
+    /// Get element of tuple by index (`(A, B, C) => B`)
+    pub mod nth;
     /// Pop element from tuple (`(A, B, T) => ((A, B), T)`)
     pub mod pop;
+    /// Take first elements of tuple (`(A, B, C) => (A, B)`)
+    pub mod prefix;
     /// Push element to tuple (`(A, B) + T => (A, B, T)`)
     pub mod push;
     /// Take element from tuple (`(T, A, B) => (T, (A, B))`)
     pub mod take;
+    /// Transpose tuple of options/results (`(Option<A>, Option<B>) =>
+    /// Option<(A, B)>`)
+    pub mod transpose;
+    /// Apply tuple of functions to tuple of arguments element-wise (`(f, g) @
+    /// (a, b) => (f(a), g(b))`)
+    pub mod zip_apply;
 }
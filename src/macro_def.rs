@@ -60,6 +60,136 @@ macro_rules! chain_many {
     };
 }
 
+/// Chains many one-to-many functions.
+///
+/// ```
+/// use fntools::chain_many_flat;
+///
+/// let range = |n: u32| 0..n;
+/// let twice = |n: u32| vec![n, n];
+/// let neg = |n: u32| Some(-(n as i64));
+///
+/// // execution order: range -> twice -> neg
+/// let fun = chain_many_flat!(range, twice, neg);
+///
+/// assert_eq!(fun(3).collect::<Vec<_>>(), [0, 0, -1, -1, -2, -2]);
+/// ```
+///
+/// ## Note
+///
+/// Without `nightly` feature this macro will use `fntools::chain_flat`, and
+/// with `nightly` feature this macro will use `fntools::unstable::chain_flat`.
+#[macro_export]
+#[cfg(feature = "nightly")]
+macro_rules! chain_many_flat {
+    ($head:expr, $tail:expr $(,)?) => {
+        $crate::unstable::chain_flat($head, $tail)
+    };
+
+    ($head:expr, $next:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::chain_many_flat!($crate::unstable::chain_flat($head, $next), $( $tail ),+)
+    };
+}
+
+/// Chains many one-to-many functions.
+///
+/// ```
+/// use fntools::chain_many_flat;
+///
+/// let range = |n: u32| 0..n;
+/// let twice = |n: u32| vec![n, n];
+/// let neg = |n: u32| Some(-(n as i64));
+///
+/// // execution order: range -> twice -> neg
+/// let fun = chain_many_flat!(range, twice, neg);
+///
+/// assert_eq!(fun(3).collect::<Vec<_>>(), [0, 0, -1, -1, -2, -2]);
+/// ```
+///
+/// ## Note
+///
+/// Without `nightly` feature this macro will use `fntools::chain_flat`, and
+/// with `nightly` feature this macro will use `fntools::unstable::chain_flat`.
+#[macro_export]
+#[cfg(not(feature = "nightly"))]
+macro_rules! chain_many_flat {
+    ($head:expr, $tail:expr $(,)?) => {
+        $crate::chain_flat($head, $tail)
+    };
+
+    ($head:expr, $next:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::chain_many_flat!($crate::chain_flat($head, $next), $( $tail ),+)
+    };
+}
+
+/// Chains many fallible functions.
+///
+/// ```
+/// use fntools::try_chain_many;
+///
+/// let parse = |s: &str| s.parse::<u8>().ok();
+/// let double = |n: u8| n.checked_mul(2);
+/// let digit = |n: u8| std::char::from_digit(n.into(), 10);
+///
+/// // execution order: parse -> double -> digit
+/// let fun = try_chain_many!(parse, double, digit);
+///
+/// assert_eq!(fun("4"), Some('8'));
+/// assert_eq!(fun("5"), None);
+/// assert_eq!(fun("200"), None);
+/// assert_eq!(fun("two"), None);
+/// ```
+///
+/// ## Note
+///
+/// Without `nightly` feature this macro will use `fntools::try_chain`, and with
+/// `nightly` feature this macro will use `fntools::unstable::try_chain`.
+#[macro_export]
+#[cfg(feature = "nightly")]
+macro_rules! try_chain_many {
+    ($head:expr, $tail:expr) => {
+        $crate::unstable::try_chain($head, $tail)
+    };
+
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::unstable::try_chain($head, $crate::try_chain_many!( $( $tail ),+ ))
+    };
+}
+
+/// Chains many fallible functions.
+///
+/// ```
+/// use fntools::try_chain_many;
+///
+/// let parse = |s: &str| s.parse::<u8>().ok();
+/// let double = |n: u8| n.checked_mul(2);
+/// let digit = |n: u8| std::char::from_digit(n.into(), 10);
+///
+/// // execution order: parse -> double -> digit
+/// let fun = try_chain_many!(parse, double, digit);
+///
+/// assert_eq!(fun("4"), Some('8'));
+/// assert_eq!(fun("5"), None);
+/// assert_eq!(fun("200"), None);
+/// assert_eq!(fun("two"), None);
+/// ```
+///
+/// ## Note
+///
+/// Without `nightly` feature this macro will use `fntools::try_chain`, and with
+/// `nightly` feature this macro will use `fntools::unstable::try_chain`.
+#[macro_export]
+#[cfg(not(feature = "nightly"))]
+macro_rules! try_chain_many {
+    ($head:expr, $tail:expr) => {
+        $crate::try_chain($head, $tail)
+    };
+
+    ($head:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::try_chain($head, $crate::try_chain_many!( $( $tail ),+ ))
+    };
+}
+
 /// Same as [`compose_many`](crate::compose_many), but this macro uses
 /// `fntools::chain_once`
 #[macro_export]
@@ -173,3 +303,113 @@ macro_rules! compose_many_mut {
         $crate::compose_mut($head, $crate::compose_many_mut!( $( $tail ),+ ))
     };
 }
+
+/// Multi-branch conditional function.
+///
+/// `cond!(p1 => f1, p2 => f2, _ => default)` returns function which applies
+/// `f` of the first branch whose predicate holds for the argument, or
+/// `default` if none of them holds (predicates are checked in order).
+///
+/// ```
+/// use fntools::{chain, cond};
+///
+/// let fizzbuzz = cond!(
+///     |n: &u32| n % 15 == 0 => |_| String::from("FizzBuzz"),
+///     |n: &u32| n % 3 == 0 => |_| String::from("Fizz"),
+///     |n: &u32| n % 5 == 0 => |_| String::from("Buzz"),
+///     _ => |n: u32| n.to_string(),
+/// );
+///
+/// let v: Vec<_> = (9..=15).map(&fizzbuzz).collect();
+/// assert_eq!(v, ["Fizz", "Buzz", "11", "Fizz", "13", "14", "FizzBuzz"]);
+///
+/// let sign = chain(
+///     |x: i32| x * 2,
+///     cond!(|x: &i32| *x < 0 => |_| -1, |x: &i32| *x > 0 => |_| 1, _ => |_| 0),
+/// );
+/// assert_eq!(sign(-7), -1);
+/// assert_eq!(sign(0), 0);
+/// ```
+///
+/// ## Note
+///
+/// This macro expands to nested [`if_else`](crate::if_else) calls.
+#[macro_export]
+macro_rules! cond {
+    (_ => $default:expr $(,)?) => {
+        $default
+    };
+
+    ($pred:expr => $f:expr, $( $rest:tt )+) => {
+        $crate::if_else($pred, $f, $crate::cond!( $( $rest )+ ))
+    };
+}
+
+/// Same as [`cond`](crate::cond), but this macro uses `fntools::if_else_once`
+#[macro_export]
+macro_rules! cond_once {
+    (_ => $default:expr $(,)?) => {
+        $default
+    };
+
+    ($pred:expr => $f:expr, $( $rest:tt )+) => {
+        $crate::if_else_once($pred, $f, $crate::cond_once!( $( $rest )+ ))
+    };
+}
+
+/// Same as [`cond`](crate::cond), but this macro uses `fntools::if_else_mut`
+#[macro_export]
+macro_rules! cond_mut {
+    (_ => $default:expr $(,)?) => {
+        $default
+    };
+
+    ($pred:expr => $f:expr, $( $rest:tt )+) => {
+        $crate::if_else_mut($pred, $f, $crate::cond_mut!( $( $rest )+ ))
+    };
+}
+
+/// Chains many functions, keeping every intermediate result.
+///
+/// Every function after the first one borrows the result of the previous one,
+/// the resulting function returns flat tuple of all results.
+///
+/// ```
+/// use fntools::chain_many_keep;
+///
+/// let tokenize = |s: &str| s.split_whitespace().map(String::from).collect::<Vec<_>>();
+/// let count = |tokens: &Vec<String>| tokens.len();
+/// let is_long = |n: &usize| *n > 2;
+///
+/// // execution order: tokenize -> count -> is_long
+/// let fun = chain_many_keep!(tokenize, count, is_long);
+///
+/// let (tokens, n, long) = fun("a b c");
+/// assert_eq!(tokens, ["a", "b", "c"]);
+/// assert_eq!(n, 3);
+/// assert!(long);
+/// ```
+///
+/// ## Note
+///
+/// This macro uses [`chain_keep`](crate::chain_keep) for the first two
+/// functions and [`chain_keep_push`](crate::chain_keep_push) for every
+/// following one.
+#[macro_export]
+macro_rules! chain_many_keep {
+    (@push $acc:expr, $next:expr) => {
+        $crate::chain_keep_push($acc, $next)
+    };
+
+    (@push $acc:expr, $next:expr, $( $tail:expr ),+) => {
+        $crate::chain_many_keep!(@push $crate::chain_keep_push($acc, $next), $( $tail ),+)
+    };
+
+    ($head:expr, $next:expr $(,)?) => {
+        $crate::chain_keep($head, $next)
+    };
+
+    ($head:expr, $next:expr, $( $tail:expr ),+ $(,)?) => {
+        $crate::chain_many_keep!(@push $crate::chain_keep($head, $next), $( $tail ),+)
+    };
+}
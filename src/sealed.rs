@@ -6,6 +6,10 @@ impl<S: Sealed> Sealed for &'_ S {}
 
 impl<S: Sealed> Sealed for &'_ mut S {}
 
+impl<T, E> Sealed for Result<T, E> {}
+
+impl<T> Sealed for Option<T> {}
+
 macro_rules! tuple_impl {
     ($( $types:ident, )*) => {
         impl<$( $types, )*> Sealed for ($( $types, )*)
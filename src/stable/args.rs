@@ -0,0 +1,101 @@
+/// Ignore argument of the function.
+///
+/// Takes function `f: () -> R` and returns `|_: A| f()`. To ignore many
+/// arguments use tuple as `A`.
+///
+/// ## Examples
+/// ```
+/// use fntools::ignore_args;
+///
+/// let answer = ignore_args(|| 42);
+/// let answers: Vec<_> = vec!["a", "b"].into_iter().map(answer).collect();
+///
+/// assert_eq!(answers, [42, 42]);
+/// ```
+///
+/// See also:
+/// - [`unstable::ignore_args`] (which ignores any number of arguments)
+///
+/// [`unstable::ignore_args`]: crate::unstable::ignore_args
+#[inline]
+pub fn ignore_args<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    F: Fn() -> R,
+{
+    move |_: A| f()
+}
+
+/// Ignore argument of the function which can be called only once.
+///
+/// See [ignore_args](self::ignore_args) for documentation.
+#[inline]
+pub fn ignore_args_once<A, R, F>(f: F) -> impl FnOnce(A) -> R
+where
+    F: FnOnce() -> R,
+{
+    move |_: A| f()
+}
+
+/// Ignore argument of the function which can be called only by unique
+/// reference.
+///
+/// See [ignore_args](self::ignore_args) for documentation.
+#[inline]
+pub fn ignore_args_mut<A, R, F>(mut f: F) -> impl FnMut(A) -> R
+where
+    F: FnMut() -> R,
+{
+    move |_: A| f()
+}
+
+/// Duplicate argument of the function.
+///
+/// Takes function `f: A, A -> B` and returns `|a: A| f(a.clone(), a)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::dup;
+/// use std::ops::Mul;
+///
+/// let square = dup(i32::mul);
+///
+/// assert_eq!(square(7), 49);
+/// ```
+///
+/// See also:
+/// - [`unstable::dup`]
+///
+/// [`unstable::dup`]: crate::unstable::dup
+#[inline]
+pub fn dup<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(A, A) -> B,
+    A: Clone,
+{
+    move |a: A| f(a.clone(), a)
+}
+
+/// Duplicate argument of the function which can be called only once.
+///
+/// See [dup](self::dup) for documentation.
+#[inline]
+pub fn dup_once<A, B, F>(f: F) -> impl FnOnce(A) -> B
+where
+    F: FnOnce(A, A) -> B,
+    A: Clone,
+{
+    move |a: A| f(a.clone(), a)
+}
+
+/// Duplicate argument of the function which can be called only by unique
+/// reference.
+///
+/// See [dup](self::dup) for documentation.
+#[inline]
+pub fn dup_mut<A, B, F>(mut f: F) -> impl FnMut(A) -> B
+where
+    F: FnMut(A, A) -> B,
+    A: Clone,
+{
+    move |a: A| f(a.clone(), a)
+}
@@ -0,0 +1,135 @@
+use std::fmt::{self, Debug, Formatter};
+
+use crate::tuple::nth::TupleNth;
+
+/// Identity function object (`|x| x`).
+///
+/// With `"nightly"` feature it implements `Fn*` traits, on stable use
+/// [`Id::to_fn`].
+///
+/// ## Examples
+/// ```
+/// use fntools::{chain, Id};
+///
+/// let add_one = chain(Id.to_fn(), |x: i32| x + 1);
+///
+/// assert_eq!(add_one(1), 2);
+/// assert_eq!(format!("{:?}", Id), "Id");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Id;
+
+impl Id {
+    /// Returns identity function for type `T`.
+    #[inline]
+    pub fn to_fn<T>(self) -> fn(T) -> T { |x| x }
+}
+
+/// Returns function object which ignores its arguments and returns (copy of)
+/// `value`.
+///
+/// ## Examples
+/// ```
+/// use fntools::constant;
+///
+/// let zero = constant(0);
+/// let zeros: Vec<_> = vec!["a", "b"].into_iter().map(zero.to_fn()).collect();
+///
+/// assert_eq!(zeros, [0, 0]);
+/// assert_eq!(format!("{:?}", zero), "Const(0)");
+/// ```
+///
+/// With `"nightly"` feature `Const` can be called with any number of
+/// arguments:
+/// ```
+/// # #[cfg(feature = "nightly")]
+/// # {
+/// use fntools::constant;
+///
+/// let four = constant(4);
+///
+/// assert_eq!(four(), 4);
+/// assert_eq!(four("x", 1, true), 4);
+/// # }
+/// ```
+#[inline]
+pub fn constant<T>(value: T) -> Const<T> { Const(value) }
+
+/// Function object which ignores its arguments and returns `T`.
+///
+/// For documentation see [`constant`].
+///
+/// [`constant`]: crate::constant
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Const<T>(pub T);
+
+impl<T> Const<T> {
+    /// Returns function of one argument which ignores it and returns (clone of)
+    /// the value.
+    #[inline]
+    pub fn to_fn<A>(self) -> impl Fn(A) -> T + Clone
+    where
+        T: Clone,
+    {
+        let Const(value) = self;
+        move |_: A| value.clone()
+    }
+
+    /// Returns inner value.
+    #[inline]
+    pub fn into_inner(self) -> T {
+        let Const(value) = self;
+        value
+    }
+}
+
+/// Returns function object which returns its `N`-th (counting from 0)
+/// argument.
+///
+/// On stable [`Proj::to_fn`] returns function which takes `N`-th element of a
+/// tuple:
+/// ```
+/// use fntools::nth_arg;
+///
+/// let pairs = vec![(1, 'a'), (2, 'b')];
+/// let letters: Vec<_> = pairs.into_iter().map(nth_arg::<1>().to_fn()).collect();
+///
+/// assert_eq!(letters, ['a', 'b']);
+/// assert_eq!(format!("{:?}", nth_arg::<1>()), "Proj::<1>");
+/// ```
+///
+/// With `"nightly"` feature `Proj` can be called directly:
+/// ```
+/// # #[cfg(feature = "nightly")]
+/// # {
+/// use fntools::nth_arg;
+///
+/// let second = nth_arg::<1>();
+/// assert_eq!(second(1, "b", 3), "b");
+/// # }
+/// ```
+#[inline]
+pub fn nth_arg<const N: usize>() -> Proj<N> { Proj }
+
+/// Function object which returns its `N`-th argument.
+///
+/// For documentation see [`nth_arg`].
+///
+/// [`nth_arg`]: crate::nth_arg
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Proj<const N: usize>;
+
+impl<const N: usize> Proj<N> {
+    /// Returns function which takes `N`-th element of a tuple `T`.
+    #[inline]
+    pub fn to_fn<T>(self) -> fn(T) -> T::Item
+    where
+        T: TupleNth<N>,
+    {
+        T::nth
+    }
+}
+
+impl<const N: usize> Debug for Proj<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { write!(f, "Proj::<{}>", N) }
+}
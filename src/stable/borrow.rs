@@ -0,0 +1,234 @@
+use std::ops::Deref;
+
+/// Pass argument to the function by reference.
+///
+/// Takes function `f: &A -> B` and returns `|a: A| f(&a)`, i.e. the returned
+/// function takes ownership of the argument and lends it to `f`.
+///
+/// # Examples
+/// ```
+/// use fntools::by_ref;
+///
+/// let lens: Vec<_> = vec![String::from("ab"), String::from("c")]
+///     .into_iter()
+///     .map(by_ref(String::len))
+///     .collect();
+///
+/// assert_eq!(lens, [2, 1]);
+/// ```
+///
+/// See also:
+/// - [`cloned`] (the opposite conversion)
+///
+/// [`cloned`]: crate::cloned
+#[inline]
+pub fn by_ref<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(&A) -> B,
+{
+    move |a: A| f(&a)
+}
+
+/// Pass argument to the function (which can be called only once) by
+/// reference.
+///
+/// See [by_ref](self::by_ref) for documentation.
+#[inline]
+pub fn by_ref_once<A, B, F>(f: F) -> impl FnOnce(A) -> B
+where
+    F: FnOnce(&A) -> B,
+{
+    move |a: A| f(&a)
+}
+
+/// Pass argument to the function (which can be called only by unique
+/// reference) by reference.
+///
+/// See [by_ref](self::by_ref) for documentation.
+#[inline]
+pub fn by_ref_mut<A, B, F>(mut f: F) -> impl FnMut(A) -> B
+where
+    F: FnMut(&A) -> B,
+{
+    move |a: A| f(&a)
+}
+
+/// Pass clone of the borrowed argument to the function.
+///
+/// Takes function `f: A -> B` and returns `|a: &A| f(a.clone())`.
+///
+/// # Examples
+/// ```
+/// use fntools::cloned;
+///
+/// let words = vec![String::from("a"), String::from("b")];
+/// let exclaim = |s: String| s + "!";
+/// let loud: Vec<_> = words.iter().map(cloned(exclaim)).collect();
+///
+/// assert_eq!(loud, ["a!", "b!"]);
+/// assert_eq!(words, ["a", "b"]);
+/// ```
+///
+/// See also:
+/// - [`by_ref`] (the opposite conversion)
+///
+/// [`by_ref`]: crate::by_ref
+#[inline]
+pub fn cloned<A, B, F>(f: F) -> impl Fn(&A) -> B
+where
+    F: Fn(A) -> B,
+    A: Clone,
+{
+    move |a: &A| f(a.clone())
+}
+
+/// Pass clone of the borrowed argument to the function (which can be called
+/// only once).
+///
+/// See [cloned](self::cloned) for documentation.
+#[inline]
+pub fn cloned_once<A, B, F>(f: F) -> impl FnOnce(&A) -> B
+where
+    F: FnOnce(A) -> B,
+    A: Clone,
+{
+    move |a: &A| f(a.clone())
+}
+
+/// Pass clone of the borrowed argument to the function (which can be called
+/// only by unique reference).
+///
+/// See [cloned](self::cloned) for documentation.
+#[inline]
+pub fn cloned_mut<A, B, F>(mut f: F) -> impl FnMut(&A) -> B
+where
+    F: FnMut(A) -> B,
+    A: Clone,
+{
+    move |a: &A| f(a.clone())
+}
+
+/// Dereference argument of the function.
+///
+/// Takes function `f: &T -> B` and returns `|u: &U| f(&*u)` where
+/// `U: Deref<Target = T>`. This is the function-level analogue of
+/// [`Apply::apply_deref`].
+///
+/// # Examples
+/// ```
+/// use fntools::deref_arg;
+/// use std::rc::Rc;
+///
+/// let is_empty = deref_arg(str::is_empty);
+///
+/// assert!(is_empty(&Rc::<str>::from("")));
+/// assert!(!is_empty(&Rc::<str>::from("rc")));
+///
+/// let total: usize = vec![Box::new(1), Box::new(2)]
+///     .iter()
+///     .map(deref_arg(|x: &usize| *x))
+///     .sum();
+/// assert_eq!(total, 3);
+/// ```
+///
+/// See also:
+/// - [`as_ref_arg`]
+///
+/// [`Apply::apply_deref`]: crate::value::Apply::apply_deref
+/// [`as_ref_arg`]: crate::as_ref_arg
+#[inline]
+pub fn deref_arg<T, U, B, F>(f: F) -> impl Fn(&U) -> B
+where
+    T: ?Sized,
+    U: Deref<Target = T> + ?Sized,
+    F: Fn(&T) -> B,
+{
+    move |u: &U| f(u)
+}
+
+/// Dereference argument of the function (which can be called only once).
+///
+/// See [deref_arg](self::deref_arg) for documentation.
+#[inline]
+pub fn deref_arg_once<T, U, B, F>(f: F) -> impl FnOnce(&U) -> B
+where
+    T: ?Sized,
+    U: Deref<Target = T> + ?Sized,
+    F: FnOnce(&T) -> B,
+{
+    move |u: &U| f(u)
+}
+
+/// Dereference argument of the function (which can be called only by unique
+/// reference).
+///
+/// See [deref_arg](self::deref_arg) for documentation.
+#[inline]
+pub fn deref_arg_mut<T, U, B, F>(mut f: F) -> impl FnMut(&U) -> B
+where
+    T: ?Sized,
+    U: Deref<Target = T> + ?Sized,
+    F: FnMut(&T) -> B,
+{
+    move |u: &U| f(u)
+}
+
+/// Convert argument of the function with `AsRef`.
+///
+/// Takes function `f: &T -> B` and returns `|u: &U| f(u.as_ref())` where
+/// `U: AsRef<T>`.
+///
+/// # Examples
+/// ```
+/// use fntools::as_ref_arg;
+/// use std::path::{Path, PathBuf};
+///
+/// let is_rust = as_ref_arg(|p: &Path| p.extension().map_or(false, |e| e == "rs"));
+///
+/// let paths = vec![PathBuf::from("src/lib.rs"), PathBuf::from("Cargo.toml")];
+/// let sources: Vec<_> = paths.iter().filter(|p| is_rust(*p)).collect();
+///
+/// assert_eq!(sources, [Path::new("src/lib.rs")]);
+/// ```
+///
+/// See also:
+/// - [`deref_arg`]
+///
+/// [`deref_arg`]: crate::deref_arg
+#[inline]
+pub fn as_ref_arg<T, U, B, F>(f: F) -> impl Fn(&U) -> B
+where
+    T: ?Sized,
+    U: AsRef<T> + ?Sized,
+    F: Fn(&T) -> B,
+{
+    move |u: &U| f(u.as_ref())
+}
+
+/// Convert argument of the function (which can be called only once) with
+/// `AsRef`.
+///
+/// See [as_ref_arg](self::as_ref_arg) for documentation.
+#[inline]
+pub fn as_ref_arg_once<T, U, B, F>(f: F) -> impl FnOnce(&U) -> B
+where
+    T: ?Sized,
+    U: AsRef<T> + ?Sized,
+    F: FnOnce(&T) -> B,
+{
+    move |u: &U| f(u.as_ref())
+}
+
+/// Convert argument of the function (which can be called only by unique
+/// reference) with `AsRef`.
+///
+/// See [as_ref_arg](self::as_ref_arg) for documentation.
+#[inline]
+pub fn as_ref_arg_mut<T, U, B, F>(mut f: F) -> impl FnMut(&U) -> B
+where
+    T: ?Sized,
+    U: AsRef<T> + ?Sized,
+    F: FnMut(&T) -> B,
+{
+    move |u: &U| f(u.as_ref())
+}
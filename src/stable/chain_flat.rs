@@ -0,0 +1,82 @@
+use std::iter::FlatMap;
+
+/// Chain two one-to-many functions.
+///
+/// Takes functions `f: A -> impl IntoIterator<Item = B>` and
+/// `g: B -> impl IntoIterator<Item = C>` and returns
+/// `|a: A| f(a).into_iter().flat_map(g)`, i.e. function which yields all `C`s
+/// produced by applying `g` to every `B` produced by `f`.
+///
+/// # Examples
+/// ```
+/// use fntools::chain_flat;
+///
+/// let words = |s: &'static str| s.split_whitespace();
+/// let chars = |w: &'static str| w.chars();
+/// let fun = chain_flat(words, chars);
+///
+/// assert_eq!(fun("ab c").collect::<String>(), "abc");
+/// ```
+///
+/// ```
+/// use fntools::chain_flat;
+///
+/// let divisors = |n: u32| (1..=n).filter(move |d| n % d == 0);
+/// let twice = |n: u32| vec![n, n];
+/// let fun = chain_flat(divisors, twice);
+///
+/// assert_eq!(fun(6).collect::<Vec<_>>(), [1, 1, 2, 2, 3, 3, 6, 6]);
+/// assert_eq!(fun(0).count(), 0);
+/// ```
+///
+/// ## Note
+///
+/// `g` is cloned on every call, since the returned iterator has to own it.
+/// That's why `g` has to be [`Fn`] even in [`chain_flat_mut`]: state mutated
+/// by a clone of `g` wouldn't be preserved between calls anyway.
+///
+/// See also:
+/// - [`unstable::chain_flat`]
+/// - [`fntools::chain`]
+///
+/// [`unstable::chain_flat`]: crate::unstable::chain_flat
+/// [`fntools::chain`]: crate::chain
+#[inline]
+pub fn chain_flat<A, I, J, F, G>(f: F, g: G) -> impl Fn(A) -> FlatMap<I::IntoIter, J, G>
+where
+    F: Fn(A) -> I,
+    I: IntoIterator,
+    G: Fn(I::Item) -> J + Clone,
+    J: IntoIterator,
+{
+    move |a: A| f(a).into_iter().flat_map(g.clone())
+}
+
+/// Chain two one-to-many functions which can be called only once.
+///
+/// See [chain_flat](self::chain_flat) for documentation.
+#[inline]
+pub fn chain_flat_once<A, I, J, F, G>(f: F, g: G) -> impl FnOnce(A) -> FlatMap<I::IntoIter, J, G>
+where
+    F: FnOnce(A) -> I,
+    I: IntoIterator,
+    G: FnMut(I::Item) -> J,
+    J: IntoIterator,
+{
+    move |a: A| f(a).into_iter().flat_map(g)
+}
+
+/// Chain two one-to-many functions which can be called only by unique
+/// reference.
+///
+/// See [chain_flat](self::chain_flat) for documentation.
+#[inline]
+pub fn chain_flat_mut<A, I, J, F, G>(mut f: F, g: G) -> impl FnMut(A) -> FlatMap<I::IntoIter, J, G>
+where
+    F: FnMut(A) -> I,
+    I: IntoIterator,
+    G: Fn(I::Item) -> J + Clone,
+    J: IntoIterator,
+{
+    move |a: A| f(a).into_iter().flat_map(g.clone())
+}
@@ -0,0 +1,206 @@
+use std::convert::TryFrom;
+
+/// Chain two functions, converting output of the first one with `From`.
+///
+/// Takes functions `f: A -> B` and `g: X -> C` where `X: From<B>` and
+/// returns `|a: A| g(X::from(f(a)))`.
+///
+/// # Examples
+/// ```
+/// use fntools::chain_into;
+///
+/// let len = |s: &str| s.len() as u32;
+/// let square = |n: u64| n * n;
+/// let fun = chain_into(len, square);
+///
+/// assert_eq!(fun("abc"), 9u64);
+/// ```
+///
+/// Crossing newtype boundaries in [`chain_many!`] pipelines:
+/// ```
+/// use fntools::{chain_into, chain_many};
+///
+/// struct Meters(f64);
+///
+/// impl From<u32> for Meters {
+///     fn from(cm: u32) -> Self { Meters(f64::from(cm) / 100.0) }
+/// }
+///
+/// let fun = chain_many!(
+///     |s: &str| s.parse::<u32>().unwrap(),
+///     chain_into(|cm: u32| cm, |m: Meters| m.0),
+///     |m: f64| format!("{}m", m),
+/// );
+///
+/// assert_eq!(fun("250"), "2.5m");
+/// ```
+///
+/// See also:
+/// - [`unstable::chain_into`]
+/// - [`try_chain_into`] (which uses `TryFrom`)
+/// - [`compose_into`]
+///
+/// [`chain_many!`]: crate::chain_many
+/// [`unstable::chain_into`]: crate::unstable::chain_into
+/// [`try_chain_into`]: crate::try_chain_into
+/// [`compose_into`]: crate::compose_into
+#[inline]
+pub fn chain_into<A, B, X, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(A) -> B,
+    X: From<B>,
+    G: Fn(X) -> C,
+{
+    move |a: A| g(X::from(f(a)))
+}
+
+/// Chain two functions which can be called only once, converting output of
+/// the first one with `From`.
+///
+/// See [chain_into](self::chain_into) for documentation.
+#[inline]
+pub fn chain_into_once<A, B, X, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    F: FnOnce(A) -> B,
+    X: From<B>,
+    G: FnOnce(X) -> C,
+{
+    move |a: A| g(X::from(f(a)))
+}
+
+/// Chain two functions which can be called only by unique reference,
+/// converting output of the first one with `From`.
+///
+/// See [chain_into](self::chain_into) for documentation.
+#[inline]
+pub fn chain_into_mut<A, B, X, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    F: FnMut(A) -> B,
+    X: From<B>,
+    G: FnMut(X) -> C,
+{
+    move |a: A| g(X::from(f(a)))
+}
+
+/// Chain two functions, converting output of the first one with `TryFrom`.
+///
+/// Takes functions `f: A -> B` and `g: X -> C` where `X: TryFrom<B>` and
+/// returns `|a: A| X::try_from(f(a)).map(g)`, i.e. `g` is called only if the
+/// conversion succeeds.
+///
+/// # Examples
+/// ```
+/// use fntools::try_chain_into;
+///
+/// let sub = |(a, b): (i64, i64)| a - b;
+/// let fun = try_chain_into(sub, |n: u8| n.count_ones());
+///
+/// assert_eq!(fun((10, 3)), Ok(3));
+/// assert!(fun((3, 10)).is_err());
+/// assert!(fun((1000, 0)).is_err());
+/// ```
+///
+/// See also:
+/// - [`unstable::try_chain_into`]
+/// - [`chain_into`]
+///
+/// [`unstable::try_chain_into`]: crate::unstable::try_chain_into
+/// [`chain_into`]: crate::chain_into
+#[inline]
+pub fn try_chain_into<A, B, X, C, F, G>(f: F, g: G) -> impl Fn(A) -> Result<C, X::Error>
+where
+    F: Fn(A) -> B,
+    X: TryFrom<B>,
+    G: Fn(X) -> C,
+{
+    move |a: A| X::try_from(f(a)).map(&g)
+}
+
+/// Chain two functions which can be called only once, converting output of
+/// the first one with `TryFrom`.
+///
+/// See [try_chain_into](self::try_chain_into) for documentation.
+#[inline]
+pub fn try_chain_into_once<A, B, X, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> Result<C, X::Error>
+where
+    F: FnOnce(A) -> B,
+    X: TryFrom<B>,
+    G: FnOnce(X) -> C,
+{
+    move |a: A| X::try_from(f(a)).map(g)
+}
+
+/// Chain two functions which can be called only by unique reference,
+/// converting output of the first one with `TryFrom`.
+///
+/// See [try_chain_into](self::try_chain_into) for documentation.
+#[inline]
+pub fn try_chain_into_mut<A, B, X, C, F, G>(
+    mut f: F,
+    mut g: G,
+) -> impl FnMut(A) -> Result<C, X::Error>
+where
+    F: FnMut(A) -> B,
+    X: TryFrom<B>,
+    G: FnMut(X) -> C,
+{
+    move |a: A| X::try_from(f(a)).map(&mut g)
+}
+
+/// Compose two functions, converting output of the second one with `From`.
+///
+/// Takes functions `f: X -> C` and `g: A -> B` where `X: From<B>` and
+/// returns `|a: A| f(X::from(g(a)))`.
+///
+/// # Examples
+/// ```
+/// use fntools::compose_into;
+///
+/// let to_f64 = compose_into(|x: f64| x / 2.0, |n: u32| n + 1);
+///
+/// assert_eq!(to_f64(2), 1.5);
+/// ```
+///
+/// See also:
+/// - [`unstable::compose_into`]
+/// - [`chain_into`]
+///
+/// [`unstable::compose_into`]: crate::unstable::compose_into
+/// [`chain_into`]: crate::chain_into
+#[inline]
+pub fn compose_into<A, B, X, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(X) -> C,
+    X: From<B>,
+    G: Fn(A) -> B,
+{
+    move |a: A| f(X::from(g(a)))
+}
+
+/// Compose two functions which can be called only once, converting output of
+/// the second one with `From`.
+///
+/// See [compose_into](self::compose_into) for documentation.
+#[inline]
+pub fn compose_into_once<A, B, X, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    F: FnOnce(X) -> C,
+    X: From<B>,
+    G: FnOnce(A) -> B,
+{
+    move |a: A| f(X::from(g(a)))
+}
+
+/// Compose two functions which can be called only by unique reference,
+/// converting output of the second one with `From`.
+///
+/// See [compose_into](self::compose_into) for documentation.
+#[inline]
+pub fn compose_into_mut<A, B, X, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    F: FnMut(X) -> C,
+    X: From<B>,
+    G: FnMut(A) -> B,
+{
+    move |a: A| f(X::from(g(a)))
+}
@@ -0,0 +1,144 @@
+use crate::tuple::{pop::TuplePop, push::TuplePush};
+
+/// Chain two functions, keeping the intermediate result.
+///
+/// Takes functions `f: A -> B` and `g: &B -> C` and returns
+/// `|a: A| { let b = f(a); let c = g(&b); (b, c) }`.
+///
+/// # Examples
+/// ```
+/// use fntools::chain_keep;
+///
+/// let parse = |s: &str| {
+///     s.split('+')
+///         .map(|n| n.trim().parse().unwrap())
+///         .collect::<Vec<i32>>()
+/// };
+/// let eval = |terms: &Vec<i32>| terms.iter().sum::<i32>();
+/// let fun = chain_keep(parse, eval);
+///
+/// assert_eq!(fun("1 + 2 + 3"), (vec![1, 2, 3], 6));
+/// ```
+///
+/// See also:
+/// - [`chain_many_keep!`] (for more than two functions)
+/// - [`fntools::chain`]
+///
+/// [`chain_many_keep!`]: crate::chain_many_keep
+/// [`fntools::chain`]: crate::chain
+#[inline]
+pub fn chain_keep<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> (B, C)
+where
+    F: Fn(A) -> B,
+    G: Fn(&B) -> C,
+{
+    move |a: A| {
+        let b = f(a);
+        let c = g(&b);
+        (b, c)
+    }
+}
+
+/// Chain two functions which can be called only once, keeping the
+/// intermediate result.
+///
+/// See [chain_keep](self::chain_keep) for documentation.
+#[inline]
+pub fn chain_keep_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> (B, C)
+where
+    F: FnOnce(A) -> B,
+    G: FnOnce(&B) -> C,
+{
+    move |a: A| {
+        let b = f(a);
+        let c = g(&b);
+        (b, c)
+    }
+}
+
+/// Chain two functions which can be called only by unique reference, keeping
+/// the intermediate result.
+///
+/// See [chain_keep](self::chain_keep) for documentation.
+#[inline]
+pub fn chain_keep_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> (B, C)
+where
+    F: FnMut(A) -> B,
+    G: FnMut(&B) -> C,
+{
+    move |a: A| {
+        let b = f(a);
+        let c = g(&b);
+        (b, c)
+    }
+}
+
+/// Chain function returning tuple with function borrowing the last element
+/// of the tuple, pushing the result to the tuple.
+///
+/// Takes functions `f: A -> (.., B)` and `g: &B -> C` and returns function
+/// `A -> (.., B, C)`. This is a building block of [`chain_many_keep!`].
+///
+/// # Examples
+/// ```
+/// use fntools::chain_keep_push;
+///
+/// let fun = chain_keep_push(|n: u32| (n, n * 2), |m: &u32| m.to_string());
+///
+/// assert_eq!(fun(21), (21, 42, String::from("42")));
+/// ```
+///
+/// See also:
+/// - [`TuplePush`]
+///
+/// [`chain_many_keep!`]: crate::chain_many_keep
+/// [`TuplePush`]: crate::tuple::push::TuplePush
+#[inline]
+pub fn chain_keep_push<A, T, C, F, G>(f: F, g: G) -> impl Fn(A) -> T::Res
+where
+    F: Fn(A) -> T,
+    T: TuplePop + TuplePush<C>,
+    G: Fn(&T::Pop) -> C,
+{
+    move |a: A| {
+        let (rem, last) = f(a).pop();
+        let c = g(&last);
+        rem.push(last).push(c)
+    }
+}
+
+/// Chain function returning tuple with function borrowing the last element
+/// of the tuple, for functions which can be called only once.
+///
+/// See [chain_keep_push](self::chain_keep_push) for documentation.
+#[inline]
+pub fn chain_keep_push_once<A, T, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> T::Res
+where
+    F: FnOnce(A) -> T,
+    T: TuplePop + TuplePush<C>,
+    G: FnOnce(&T::Pop) -> C,
+{
+    move |a: A| {
+        let (rem, last) = f(a).pop();
+        let c = g(&last);
+        rem.push(last).push(c)
+    }
+}
+
+/// Chain function returning tuple with function borrowing the last element
+/// of the tuple, for functions which can be called only by unique reference.
+///
+/// See [chain_keep_push](self::chain_keep_push) for documentation.
+#[inline]
+pub fn chain_keep_push_mut<A, T, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> T::Res
+where
+    F: FnMut(A) -> T,
+    T: TuplePop + TuplePush<C>,
+    G: FnMut(&T::Pop) -> C,
+{
+    move |a: A| {
+        let (rem, last) = f(a).pop();
+        let c = g(&last);
+        rem.push(last).push(c)
+    }
+}
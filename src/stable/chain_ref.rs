@@ -0,0 +1,286 @@
+/// Chain two functions where the first one returns borrow of its argument.
+///
+/// Takes functions `f: for<'a> &'a A -> &'a B` and `g: &B -> C` and returns
+/// `for<'a> |a: &'a A| g(f(a))`. Unlike [`chain`] this works with functions
+/// generic over lifetimes (such as field accessors).
+///
+/// > Note: intermediate type `B` must be `'static` (this is a limitation of
+/// > higher-ranked closures, see [`unstable::chain_ref`] for version without
+/// > it).
+///
+/// # Examples
+/// ```
+/// use fntools::chain_ref;
+///
+/// struct User {
+///     name: String,
+/// }
+///
+/// let name_len = chain_ref(|u: &User| &u.name, |name: &String| name.len());
+///
+/// let users = vec![
+///     User {
+///         name: String::from("Alice"),
+///     },
+///     User {
+///         name: String::from("Bob"),
+///     },
+/// ];
+/// let lens: Vec<_> = users.iter().map(name_len).collect();
+///
+/// assert_eq!(lens, [5, 3]);
+/// ```
+///
+/// See also:
+/// - [`chain_proj`] (for functions which both return borrows)
+/// - [`compose_ref`]
+/// - [`unstable::chain_ref`]
+///
+/// [`chain`]: crate::chain
+/// [`chain_proj`]: crate::chain_proj
+/// [`compose_ref`]: crate::compose_ref
+/// [`unstable::chain_ref`]: crate::unstable::chain_ref
+#[inline]
+pub fn chain_ref<A, B, C, F, G>(f: F, g: G) -> impl Fn(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: Fn(&A) -> &B,
+    G: Fn(&B) -> C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Chain two functions (which can be called only once) where the first one
+/// returns borrow of its argument.
+///
+/// See [chain_ref](self::chain_ref) for documentation.
+#[inline]
+pub fn chain_ref_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: FnOnce(&A) -> &B,
+    G: FnOnce(&B) -> C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Chain two functions (which can be called only by unique reference) where
+/// the first one returns borrow of its argument.
+///
+/// See [chain_ref](self::chain_ref) for documentation.
+#[inline]
+pub fn chain_ref_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: FnMut(&A) -> &B,
+    G: FnMut(&B) -> C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Chain two projections (functions returning borrows of their arguments).
+///
+/// Takes functions `f: for<'a> &'a A -> &'a B` and
+/// `g: for<'b> &'b B -> &'b C` and returns `for<'a> |a: &'a A| -> &'a C`.
+///
+/// > Note: intermediate type `B` must be `'static`, see [`chain_ref`].
+///
+/// # Examples
+/// ```
+/// use fntools::chain_proj;
+///
+/// struct Request {
+///     headers: Vec<(String, String)>,
+/// }
+///
+/// let first_header = chain_proj(
+///     |r: &Request| &r.headers[0],
+///     |(_, value): &(String, String)| value.as_str(),
+/// );
+///
+/// let req = Request {
+///     headers: vec![(String::from("Host"), String::from("example.com"))],
+/// };
+/// let host: &str = first_header(&req);
+///
+/// assert_eq!(host, "example.com");
+/// ```
+///
+/// See also:
+/// - [`chain_ref`]
+/// - [`compose_proj`]
+///
+/// [`chain_ref`]: crate::chain_ref
+/// [`compose_proj`]: crate::compose_proj
+#[inline]
+pub fn chain_proj<A, B, C, F, G>(f: F, g: G) -> impl Fn(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: Fn(&A) -> &B,
+    G: Fn(&B) -> &C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Chain two projections which can be called only once.
+///
+/// See [chain_proj](self::chain_proj) for documentation.
+#[inline]
+pub fn chain_proj_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: FnOnce(&A) -> &B,
+    G: FnOnce(&B) -> &C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Chain two projections which can be called only by unique reference.
+///
+/// See [chain_proj](self::chain_proj) for documentation.
+#[inline]
+pub fn chain_proj_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: FnMut(&A) -> &B,
+    G: FnMut(&B) -> &C,
+{
+    move |a: &A| g(f(a))
+}
+
+/// Compose two functions where the second one returns borrow of its
+/// argument.
+///
+/// Takes functions `f: &B -> C` and `g: for<'a> &'a A -> &'a B` and returns
+/// `for<'a> |a: &'a A| f(g(a))`.
+///
+/// > Note: intermediate type `B` must be `'static`, see [`chain_ref`].
+///
+/// # Examples
+/// ```
+/// use fntools::compose_ref;
+///
+/// let is_blank = compose_ref(str::is_empty, str::trim);
+///
+/// assert!(is_blank("  "));
+/// assert!(!is_blank(" a "));
+/// ```
+///
+/// See also:
+/// - [`chain_ref`]
+/// - [`unstable::compose_ref`]
+///
+/// [`chain_ref`]: crate::chain_ref
+/// [`unstable::compose_ref`]: crate::unstable::compose_ref
+#[inline]
+pub fn compose_ref<A, B, C, F, G>(f: F, g: G) -> impl Fn(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: Fn(&B) -> C,
+    G: Fn(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
+
+/// Compose two functions (which can be called only once) where the second one
+/// returns borrow of its argument.
+///
+/// See [compose_ref](self::compose_ref) for documentation.
+#[inline]
+pub fn compose_ref_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: FnOnce(&B) -> C,
+    G: FnOnce(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
+
+/// Compose two functions (which can be called only by unique reference) where
+/// the second one returns borrow of its argument.
+///
+/// See [compose_ref](self::compose_ref) for documentation.
+#[inline]
+pub fn compose_ref_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(&A) -> C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    F: FnMut(&B) -> C,
+    G: FnMut(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
+
+/// Compose two projections (functions returning borrows of their arguments).
+///
+/// Takes functions `f: for<'b> &'b B -> &'b C` and
+/// `g: for<'a> &'a A -> &'a B` and returns `for<'a> |a: &'a A| -> &'a C`.
+///
+/// > Note: intermediate type `B` must be `'static`, see [`chain_ref`].
+///
+/// # Examples
+/// ```
+/// use fntools::compose_proj;
+///
+/// let first_line = compose_proj(|s: &str| s.lines().next().unwrap_or(""), str::trim_start);
+///
+/// assert_eq!(first_line("  hello\nworld"), "hello");
+/// ```
+///
+/// See also:
+/// - [`chain_proj`]
+///
+/// [`chain_proj`]: crate::chain_proj
+/// [`chain_ref`]: crate::chain_ref
+#[inline]
+pub fn compose_proj<A, B, C, F, G>(f: F, g: G) -> impl Fn(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: Fn(&B) -> &C,
+    G: Fn(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
+
+/// Compose two projections which can be called only once.
+///
+/// See [compose_proj](self::compose_proj) for documentation.
+#[inline]
+pub fn compose_proj_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: FnOnce(&B) -> &C,
+    G: FnOnce(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
+
+/// Compose two projections which can be called only by unique reference.
+///
+/// See [compose_proj](self::compose_proj) for documentation.
+#[inline]
+pub fn compose_proj_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(&A) -> &C
+where
+    A: ?Sized,
+    B: ?Sized + 'static,
+    C: ?Sized,
+    F: FnMut(&B) -> &C,
+    G: FnMut(&A) -> &B,
+{
+    move |a: &A| f(g(a))
+}
@@ -0,0 +1,140 @@
+/// Chain two functions, giving the second one access to the original input.
+///
+/// Takes functions `f: &A -> B` and `g: &A, B -> C` and returns
+/// `|a: A| g(&a, f(&a))`.
+///
+/// # Examples
+/// ```
+/// use fntools::chain_with_input;
+///
+/// let parse = |s: &String| s.parse::<u8>();
+/// let report =
+///     |s: &String, res: Result<u8, _>| res.map_err(|e| format!("can't parse {:?}: {}", s, e));
+/// let fun = chain_with_input(parse, report);
+///
+/// assert_eq!(fun(String::from("42")), Ok(42));
+/// assert_eq!(
+///     fun(String::from("x")),
+///     Err(String::from(
+///         "can't parse \"x\": invalid digit found in string"
+///     ))
+/// );
+/// ```
+///
+/// See also:
+/// - [`chain_with_input_owned`] (which passes input by value)
+/// - [`unstable::chain_with_input`]
+///
+/// [`chain_with_input_owned`]: crate::chain_with_input_owned
+/// [`unstable::chain_with_input`]: crate::unstable::chain_with_input
+#[inline]
+pub fn chain_with_input<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(&A) -> B,
+    G: Fn(&A, B) -> C,
+{
+    move |a: A| {
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+/// Chain two functions which can be called only once, giving the second one
+/// access to the original input.
+///
+/// See [chain_with_input](self::chain_with_input) for documentation.
+#[inline]
+pub fn chain_with_input_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    F: FnOnce(&A) -> B,
+    G: FnOnce(&A, B) -> C,
+{
+    move |a: A| {
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+/// Chain two functions which can be called only by unique reference, giving
+/// the second one access to the original input.
+///
+/// See [chain_with_input](self::chain_with_input) for documentation.
+#[inline]
+pub fn chain_with_input_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    F: FnMut(&A) -> B,
+    G: FnMut(&A, B) -> C,
+{
+    move |a: A| {
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+/// Chain two functions, giving the second one the original input by value.
+///
+/// Takes functions `f: A -> B` and `g: A, B -> C` and returns
+/// `|a: A| g(a.clone(), f(a))`.
+///
+/// # Examples
+/// ```
+/// use fntools::chain_with_input_owned;
+///
+/// let fun = chain_with_input_owned(
+///     |v: Vec<i32>| v.len(),
+///     |mut v: Vec<i32>, len| {
+///         v.push(len as i32);
+///         v
+///     },
+/// );
+///
+/// assert_eq!(fun(vec![7, 7]), [7, 7, 2]);
+/// ```
+///
+/// See also:
+/// - [`chain_with_input`] (which passes input by reference)
+/// - [`unstable::chain_with_input_owned`]
+/// - [`combinators::s`] (with the order of functions flipped)
+///
+/// [`chain_with_input`]: crate::chain_with_input
+/// [`unstable::chain_with_input_owned`]: crate::unstable::chain_with_input_owned
+/// [`combinators::s`]: crate::combinators::s
+#[inline]
+pub fn chain_with_input_owned<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    A: Clone,
+    F: Fn(A) -> B,
+    G: Fn(A, B) -> C,
+{
+    move |a: A| g(a.clone(), f(a))
+}
+
+/// Chain two functions which can be called only once, giving the second one
+/// the original input by value.
+///
+/// See [chain_with_input_owned](self::chain_with_input_owned) for
+/// documentation.
+#[inline]
+pub fn chain_with_input_owned_once<A, B, C, F, G>(f: F, g: G) -> impl FnOnce(A) -> C
+where
+    A: Clone,
+    F: FnOnce(A) -> B,
+    G: FnOnce(A, B) -> C,
+{
+    move |a: A| g(a.clone(), f(a))
+}
+
+/// Chain two functions which can be called only by unique reference, giving
+/// the second one the original input by value.
+///
+/// See [chain_with_input_owned](self::chain_with_input_owned) for
+/// documentation.
+#[inline]
+pub fn chain_with_input_owned_mut<A, B, C, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> C
+where
+    A: Clone,
+    F: FnMut(A) -> B,
+    G: FnMut(A, B) -> C,
+{
+    move |a: A| g(a.clone(), f(a))
+}
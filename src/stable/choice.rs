@@ -0,0 +1,245 @@
+/// Choice between two functions (`f ||| g`).
+///
+/// Takes functions `f` and `g` and returns function which applies `f` to `Ok`
+/// and `g` to `Err`, merging both branches into a common output:
+/// `|r: Result<A, X>| match r { Ok(a) => f(a), Err(x) => g(x) }`.
+///
+/// # Examples
+/// ```
+/// use fntools::choice;
+///
+/// let describe = choice(
+///     |n: i32| format!("number {}", n),
+///     |e: &str| format!("error: {}", e),
+/// );
+///
+/// assert_eq!(describe(Ok(17)), "number 17");
+/// assert_eq!(describe(Err("oops")), "error: oops");
+/// ```
+///
+/// ```
+/// use fntools::choice;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let or_zero = choice(|n| n, |_| 0);
+///
+/// assert_eq!(or_zero(parse("42")), 42);
+/// assert_eq!(or_zero(parse("forty two")), 0);
+/// ```
+///
+/// See also:
+/// - [`unstable::choice`]
+/// - [`fntools::bimap`]
+///
+/// [`unstable::choice`]: crate::unstable::choice
+/// [`fntools::bimap`]: crate::bimap
+#[inline]
+pub fn choice<A, X, C, F, G>(f: F, g: G) -> impl Fn(Result<A, X>) -> C
+where
+    F: Fn(A) -> C,
+    G: Fn(X) -> C,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => f(a),
+        Err(x) => g(x),
+    }
+}
+
+/// Choice between two functions which can be called only once.
+///
+/// See [choice](self::choice) for documentation.
+#[inline]
+pub fn choice_once<A, X, C, F, G>(f: F, g: G) -> impl FnOnce(Result<A, X>) -> C
+where
+    F: FnOnce(A) -> C,
+    G: FnOnce(X) -> C,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => f(a),
+        Err(x) => g(x),
+    }
+}
+
+/// Choice between two functions which can be called only by unique reference.
+///
+/// See [choice](self::choice) for documentation.
+#[inline]
+pub fn choice_mut<A, X, C, F, G>(mut f: F, mut g: G) -> impl FnMut(Result<A, X>) -> C
+where
+    F: FnMut(A) -> C,
+    G: FnMut(X) -> C,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => f(a),
+        Err(x) => g(x),
+    }
+}
+
+/// Map both sides of a `Result` (`f +++ g`).
+///
+/// Takes functions `f` and `g` and returns function which applies `f` to `Ok`
+/// and `g` to `Err`, keeping the branch:
+/// `|r: Result<A, X>| match r { Ok(a) => Ok(f(a)), Err(x) => Err(g(x)) }`.
+///
+/// # Examples
+/// ```
+/// use fntools::bimap;
+///
+/// let fun = bimap(|n: i32| n * 2, str::len);
+///
+/// assert_eq!(fun(Ok(4)), Ok(8));
+/// assert_eq!(fun(Err("four")), Err(4));
+/// ```
+///
+/// See also:
+/// - [`unstable::bimap`]
+/// - [`fntools::choice`]
+///
+/// [`unstable::bimap`]: crate::unstable::bimap
+/// [`fntools::choice`]: crate::choice
+#[inline]
+pub fn bimap<A, B, X, Y, F, G>(f: F, g: G) -> impl Fn(Result<A, X>) -> Result<B, Y>
+where
+    F: Fn(A) -> B,
+    G: Fn(X) -> Y,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => Ok(f(a)),
+        Err(x) => Err(g(x)),
+    }
+}
+
+/// Map both sides of a `Result` with functions which can be called only once.
+///
+/// See [bimap](self::bimap) for documentation.
+#[inline]
+pub fn bimap_once<A, B, X, Y, F, G>(f: F, g: G) -> impl FnOnce(Result<A, X>) -> Result<B, Y>
+where
+    F: FnOnce(A) -> B,
+    G: FnOnce(X) -> Y,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => Ok(f(a)),
+        Err(x) => Err(g(x)),
+    }
+}
+
+/// Map both sides of a `Result` with functions which can be called only by
+/// unique reference.
+///
+/// See [bimap](self::bimap) for documentation.
+#[inline]
+pub fn bimap_mut<A, B, X, Y, F, G>(mut f: F, mut g: G) -> impl FnMut(Result<A, X>) -> Result<B, Y>
+where
+    F: FnMut(A) -> B,
+    G: FnMut(X) -> Y,
+{
+    move |r: Result<A, X>| match r {
+        Ok(a) => Ok(f(a)),
+        Err(x) => Err(g(x)),
+    }
+}
+
+/// Map the left (`Ok`) side of a `Result`, passing `Err` through.
+///
+/// i.e. `|r: Result<A, X>| r.map(f)`.
+///
+/// # Examples
+/// ```
+/// use fntools::left;
+///
+/// let fun = left(|n: i32| n + 1);
+///
+/// assert_eq!(fun(Ok::<_, ()>(1)), Ok(2));
+/// assert_eq!(fun(Err(())), Err(()));
+/// ```
+///
+/// See also:
+/// - [`unstable::left`]
+/// - [`fntools::right`]
+///
+/// [`unstable::left`]: crate::unstable::left
+/// [`fntools::right`]: crate::right
+#[inline]
+pub fn left<A, B, X, F>(f: F) -> impl Fn(Result<A, X>) -> Result<B, X>
+where
+    F: Fn(A) -> B,
+{
+    move |r: Result<A, X>| r.map(&f)
+}
+
+/// Map the left (`Ok`) side of a `Result` with function which can be called
+/// only once.
+///
+/// See [left](self::left) for documentation.
+#[inline]
+pub fn left_once<A, B, X, F>(f: F) -> impl FnOnce(Result<A, X>) -> Result<B, X>
+where
+    F: FnOnce(A) -> B,
+{
+    move |r: Result<A, X>| r.map(f)
+}
+
+/// Map the left (`Ok`) side of a `Result` with function which can be called
+/// only by unique reference.
+///
+/// See [left](self::left) for documentation.
+#[inline]
+pub fn left_mut<A, B, X, F>(mut f: F) -> impl FnMut(Result<A, X>) -> Result<B, X>
+where
+    F: FnMut(A) -> B,
+{
+    move |r: Result<A, X>| r.map(&mut f)
+}
+
+/// Map the right (`Err`) side of a `Result`, passing `Ok` through.
+///
+/// i.e. `|r: Result<A, X>| r.map_err(f)`.
+///
+/// # Examples
+/// ```
+/// use fntools::right;
+///
+/// let fun = right(str::len);
+///
+/// assert_eq!(fun(Ok::<i32, _>(1)), Ok(1));
+/// assert_eq!(fun(Err("four")), Err(4));
+/// ```
+///
+/// See also:
+/// - [`unstable::right`]
+/// - [`fntools::left`]
+///
+/// [`unstable::right`]: crate::unstable::right
+/// [`fntools::left`]: crate::left
+#[inline]
+pub fn right<A, X, Y, F>(f: F) -> impl Fn(Result<A, X>) -> Result<A, Y>
+where
+    F: Fn(X) -> Y,
+{
+    move |r: Result<A, X>| r.map_err(&f)
+}
+
+/// Map the right (`Err`) side of a `Result` with function which can be called
+/// only once.
+///
+/// See [right](self::right) for documentation.
+#[inline]
+pub fn right_once<A, X, Y, F>(f: F) -> impl FnOnce(Result<A, X>) -> Result<A, Y>
+where
+    F: FnOnce(X) -> Y,
+{
+    move |r: Result<A, X>| r.map_err(f)
+}
+
+/// Map the right (`Err`) side of a `Result` with function which can be called
+/// only by unique reference.
+///
+/// See [right](self::right) for documentation.
+#[inline]
+pub fn right_mut<A, X, Y, F>(mut f: F) -> impl FnMut(Result<A, X>) -> Result<A, Y>
+where
+    F: FnMut(X) -> Y,
+{
+    move |r: Result<A, X>| r.map_err(&mut f)
+}
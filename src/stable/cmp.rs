@@ -0,0 +1,380 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+/// Make comparator from key-extraction function.
+///
+/// Returns `|a: &T, b: &T| key(a).cmp(&key(b))`, use it with `sort_by`,
+/// `max_by` and similar methods.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::{comparing, reversed, then_comparing};
+///
+/// struct Person {
+///     name: &'static str,
+///     age: u32,
+/// }
+///
+/// let mut people = vec![
+///     Person {
+///         name: "Bob",
+///         age: 30,
+///     },
+///     Person {
+///         name: "Alice",
+///         age: 30,
+///     },
+///     Person {
+///         name: "Eve",
+///         age: 25,
+///     },
+/// ];
+///
+/// people.sort_by(then_comparing(
+///     comparing(|p: &Person| p.age),
+///     |p: &Person| p.name,
+/// ));
+/// let names: Vec<_> = people.iter().map(|p| p.name).collect();
+/// assert_eq!(names, ["Eve", "Alice", "Bob"]);
+///
+/// people.sort_by(reversed(comparing(|p: &Person| p.age)));
+/// assert_eq!(people[2].name, "Eve");
+/// ```
+///
+/// See also:
+/// - [`then_comparing`], [`then`], [`reversed`]
+/// - [`nulls_first`], [`nulls_last`]
+/// - [`ByKey`]
+///
+/// [`ByKey`]: crate::cmp::ByKey
+/// [`then_comparing`]: crate::cmp::then_comparing
+/// [`then`]: crate::cmp::then
+/// [`reversed`]: crate::cmp::reversed
+/// [`nulls_first`]: crate::cmp::nulls_first
+/// [`nulls_last`]: crate::cmp::nulls_last
+#[inline]
+pub fn comparing<T, K, F>(key: F) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    K: Ord,
+    F: Fn(&T) -> K,
+{
+    move |a: &T, b: &T| key(a).cmp(&key(b))
+}
+
+/// Make comparator from `Option`al key, placing `None`s first.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::nulls_first;
+///
+/// let mut v = vec![Some(2), None, Some(1)];
+/// v.sort_by(nulls_first(|x: &Option<i32>| *x));
+///
+/// assert_eq!(v, [None, Some(1), Some(2)]);
+/// ```
+#[inline]
+pub fn nulls_first<T, K, F>(key: F) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    K: Ord,
+    F: Fn(&T) -> Option<K>,
+{
+    move |a: &T, b: &T| match (key(a), key(b)) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Less,
+        (Some(_), None) => Ordering::Greater,
+        (Some(a), Some(b)) => a.cmp(&b),
+    }
+}
+
+/// Make comparator from `Option`al key, placing `None`s last.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::nulls_last;
+///
+/// let mut v = vec![Some(2), None, Some(1)];
+/// v.sort_by(nulls_last(|x: &Option<i32>| *x));
+///
+/// assert_eq!(v, [Some(1), Some(2), None]);
+/// ```
+#[inline]
+pub fn nulls_last<T, K, F>(key: F) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    K: Ord,
+    F: Fn(&T) -> Option<K>,
+{
+    move |a: &T, b: &T| match (key(a), key(b)) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => a.cmp(&b),
+    }
+}
+
+/// Apply binary function to keys of both arguments:
+/// `on(f, key)(a, b) = f(key(a), key(b))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::on;
+///
+/// let same_len = on(|a: usize, b: usize| a == b, |s: &str| s.len());
+/// assert!(same_len("abc", "xyz"));
+///
+/// let mut words = vec!["ccc", "a", "bb"];
+/// words.sort_by(on(|a: usize, b: usize| a.cmp(&b), |s: &&str| s.len()));
+/// assert_eq!(words, ["a", "bb", "ccc"]);
+/// ```
+///
+/// See also:
+/// - [`combinators::psi`] (which takes arguments by value)
+///
+/// [`combinators::psi`]: crate::combinators::psi
+#[inline]
+pub fn on<T, K, R, F, G>(f: F, key: G) -> impl Fn(&T, &T) -> R
+where
+    T: ?Sized,
+    F: Fn(K, K) -> R,
+    G: Fn(&T) -> K,
+{
+    move |a: &T, b: &T| f(key(a), key(b))
+}
+
+/// Compare by `key` if comparator `cmp` considers values equal:
+/// `then_comparing(cmp, key) = then(cmp, comparing(key))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::{comparing, then_comparing};
+///
+/// let mut v = vec!["bb", "a", "ab", "b"];
+/// v.sort_by(then_comparing(comparing(|s: &&str| s.len()), |s: &&str| *s));
+///
+/// assert_eq!(v, ["a", "b", "ab", "bb"]);
+/// ```
+#[inline]
+pub fn then_comparing<T, K, C, F>(cmp: C, key: F) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    K: Ord,
+    C: Fn(&T, &T) -> Ordering,
+    F: Fn(&T) -> K,
+{
+    then(cmp, comparing(key))
+}
+
+/// Compare by `other` if comparator `cmp` considers values equal.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::{comparing, nulls_last, reversed, then};
+///
+/// let mut v = vec![(1, Some('b')), (0, None), (1, None), (0, Some('a'))];
+/// v.sort_by(then(
+///     reversed(comparing(|&(n, _): &(i32, Option<char>)| n)),
+///     nulls_last(|&(_, c): &(i32, Option<char>)| c),
+/// ));
+///
+/// assert_eq!(v, [(1, Some('b')), (1, None), (0, Some('a')), (0, None)]);
+/// ```
+#[inline]
+pub fn then<T, C, D>(cmp: C, other: D) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    C: Fn(&T, &T) -> Ordering,
+    D: Fn(&T, &T) -> Ordering,
+{
+    move |a: &T, b: &T| cmp(a, b).then_with(|| other(a, b))
+}
+
+/// Reverse the order of comparator `cmp`.
+///
+/// ## Examples
+/// ```
+/// use fntools::cmp::{comparing, reversed};
+///
+/// let mut v = vec![2, 3, 1];
+/// v.sort_by(reversed(comparing(|x: &i32| *x)));
+///
+/// assert_eq!(v, [3, 2, 1]);
+/// ```
+#[inline]
+pub fn reversed<T, C>(cmp: C) -> impl Fn(&T, &T) -> Ordering
+where
+    T: ?Sized,
+    C: Fn(&T, &T) -> Ordering,
+{
+    move |a: &T, b: &T| cmp(b, a)
+}
+
+/// Value compared, ordered and hashed by a key projected with function `F`.
+///
+/// `ByKey` implements [`PartialEq`], [`Eq`], [`PartialOrd`], [`Ord`] and
+/// [`Hash`] by comparing/hashing `key(&value)`, so records can be stored in
+/// `HashSet`/`BTreeSet` (or used as map keys) keyed by one of their fields
+/// without writing a newtype with manual impls.
+///
+/// Note that the key function is called on every comparison, so it should be
+/// cheap. Both compared values must have the same key function type, use
+/// non-capturing closures or `fn` pointers (the latter can be named in
+/// types, e.g. `ByKey<User, fn(&User) -> u32>`).
+///
+/// ## Examples
+/// ```
+/// use fntools::{cmp::ByKey, value::Apply};
+/// use std::collections::{BTreeSet, HashSet};
+///
+/// #[derive(Debug)]
+/// struct User {
+///     id: u32,
+///     name: &'static str,
+/// }
+///
+/// let id = |u: &User| u.apply_ref(|u| u.id);
+///
+/// let mut set = HashSet::new();
+/// assert!(set.insert(ByKey::new(
+///     User {
+///         id: 1,
+///         name: "Alice"
+///     },
+///     id
+/// )));
+/// assert!(set.insert(ByKey::new(User { id: 2, name: "Bob" }, id)));
+/// // same id, different name: treated as a duplicate
+/// assert!(!set.insert(ByKey::new(User { id: 1, name: "Eve" }, id)));
+/// assert_eq!(set.len(), 2);
+///
+/// let by_name: BTreeSet<ByKey<User, fn(&User) -> &'static str>> = vec![
+///     User {
+///         id: 3,
+///         name: "Carol",
+///     },
+///     User {
+///         id: 1,
+///         name: "Alice",
+///     },
+///     User { id: 2, name: "Bob" },
+/// ]
+/// .into_iter()
+/// .map(|u| ByKey::new(u, (|u: &User| u.name) as fn(&User) -> _))
+/// .collect();
+///
+/// // `ByKey` derefs to the wrapped value
+/// let names: Vec<_> = by_name.iter().map(|u| u.name).collect();
+/// assert_eq!(names, ["Alice", "Bob", "Carol"]);
+/// ```
+///
+/// See also:
+/// - [`comparing`]
+#[derive(Clone, Copy)]
+pub struct ByKey<T, F> {
+    value: T,
+    key: F,
+}
+
+impl<T, F> ByKey<T, F> {
+    /// Wraps `value` so it's compared by `key(&value)`.
+    #[inline]
+    pub fn new<K>(value: T, key: F) -> Self
+    where
+        F: Fn(&T) -> K,
+    {
+        ByKey { value, key }
+    }
+
+    /// Returns key of the wrapped value.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::cmp::ByKey;
+    ///
+    /// let word = ByKey::new("hello", |s: &&str| s.len());
+    /// assert_eq!(word.key(), 5);
+    /// ```
+    #[inline]
+    pub fn key<K>(&self) -> K
+    where
+        F: Fn(&T) -> K,
+    {
+        (self.key)(&self.value)
+    }
+
+    /// Returns reference to the wrapped value.
+    #[inline]
+    pub fn get(&self) -> &T { &self.value }
+
+    /// Returns the wrapped value.
+    #[inline]
+    pub fn into_inner(self) -> T { self.value }
+
+    /// Returns the wrapped value and the key function.
+    #[inline]
+    pub fn into_parts(self) -> (T, F) { (self.value, self.key) }
+}
+
+impl<T, F> Deref for ByKey<T, F> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target { &self.value }
+}
+
+impl<T, F, K> PartialEq for ByKey<T, F>
+where
+    F: Fn(&T) -> K,
+    K: PartialEq,
+{
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.key() == other.key() }
+}
+
+impl<T, F, K> Eq for ByKey<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Eq,
+{
+}
+
+impl<T, F, K> PartialOrd for ByKey<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+
+impl<T, F, K> Ord for ByKey<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Ord,
+{
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering { self.key().cmp(&other.key()) }
+}
+
+impl<T, F, K> Hash for ByKey<T, F>
+where
+    F: Fn(&T) -> K,
+    K: Hash,
+{
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.key().hash(state) }
+}
+
+impl<T, F> fmt::Debug for ByKey<T, F>
+where
+    T: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ByKey").field(&self.value).finish()
+    }
+}
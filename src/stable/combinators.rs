@@ -0,0 +1,154 @@
+/// B combinator (bluebird), composition: `b(f, g)(x) = f(g(x))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::b;
+///
+/// let len_plus_one = b(|n: usize| n + 1, str::len);
+///
+/// assert_eq!(len_plus_one("abc"), 4);
+/// ```
+#[inline]
+pub fn b<A, B, C, F, G>(f: F, g: G) -> impl Fn(A) -> C
+where
+    F: Fn(B) -> C,
+    G: Fn(A) -> B,
+{
+    crate::compose(f, g)
+}
+
+/// C combinator (cardinal), flipping arguments: `c(f)(x, y) = f(y, x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::c;
+/// use std::ops::Sub;
+///
+/// let rsub = c(i32::sub);
+///
+/// assert_eq!(rsub(1, 10), 9);
+/// ```
+#[inline]
+pub fn c<A, B, R, F>(f: F) -> impl Fn(B, A) -> R
+where
+    F: Fn(A, B) -> R,
+{
+    crate::flip(f)
+}
+
+/// K combinator (kestrel), constant function: `k(x)(y) = x`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::k;
+///
+/// let always_7 = k(7);
+///
+/// assert_eq!(always_7("whatever"), 7);
+/// ```
+#[inline]
+pub fn k<A, T>(x: T) -> impl Fn(A) -> T
+where
+    T: Clone,
+{
+    crate::constant(x).to_fn()
+}
+
+/// I combinator (identity): `i()(x) = x`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::i;
+///
+/// let words: Vec<_> = vec!["a", "b"].into_iter().map(i()).collect();
+///
+/// assert_eq!(words, ["a", "b"]);
+/// ```
+#[inline]
+pub fn i<T>() -> impl Fn(T) -> T { |x| x }
+
+/// S combinator (starling): `s(f, g)(x) = f(x, g(x))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::s;
+///
+/// let with_len = s(|s: &str, len: usize| format!("{}:{}", s, len), str::len);
+///
+/// assert_eq!(with_len("abc"), "abc:3");
+/// ```
+#[inline]
+pub fn s<A, B, R, F, G>(f: F, g: G) -> impl Fn(A) -> R
+where
+    F: Fn(A, B) -> R,
+    G: Fn(A) -> B,
+    A: Clone,
+{
+    move |x: A| {
+        let gx = g(x.clone());
+        f(x, gx)
+    }
+}
+
+/// W combinator (warbler), duplicating argument: `w(f)(x) = f(x, x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::w;
+/// use std::ops::Mul;
+///
+/// let square = w(u32::mul);
+///
+/// assert_eq!(square(12), 144);
+/// ```
+#[inline]
+pub fn w<A, B, F>(f: F) -> impl Fn(A) -> B
+where
+    F: Fn(A, A) -> B,
+    A: Clone,
+{
+    crate::dup(f)
+}
+
+/// Φ combinator (phoenix), fork: `phi(f, g, h)(x) = f(g(x), h(x))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::phi;
+///
+/// let sum = |xs: &[f64]| xs.iter().sum::<f64>();
+/// let len = |xs: &[f64]| xs.len() as f64;
+/// let mean = phi(|s: f64, l: f64| s / l, sum, len);
+///
+/// assert_eq!(mean(&[1.0, 2.0, 6.0]), 3.0);
+/// ```
+#[inline]
+pub fn phi<A, B, C, R, F, G, H>(f: F, g: G, h: H) -> impl Fn(A) -> R
+where
+    F: Fn(B, C) -> R,
+    G: Fn(A) -> B,
+    H: Fn(A) -> C,
+    A: Clone,
+{
+    move |x: A| f(g(x.clone()), h(x))
+}
+
+/// Ψ combinator (psi), `on`: `psi(f, g)(x, y) = f(g(x), g(y))`.
+///
+/// ## Examples
+/// ```
+/// use fntools::combinators::psi;
+///
+/// let same_len = psi(|a: usize, b: usize| a == b, str::len);
+///
+/// assert!(same_len("abc", "xyz"));
+/// assert!(!same_len("abc", "xy"));
+/// ```
+#[inline]
+pub fn psi<A, B, R, F, G>(f: F, g: G) -> impl Fn(A, A) -> R
+where
+    F: Fn(B, B) -> R,
+    G: Fn(A) -> B,
+{
+    move |x: A, y: A| f(g(x), g(y))
+}
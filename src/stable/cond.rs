@@ -0,0 +1,227 @@
+/// Apply function only when predicate holds.
+///
+/// Takes predicate `pred` and function `f` and returns
+/// `|a: A| if pred(&a) { f(a) } else { a }`.
+///
+/// # Examples
+/// ```
+/// use fntools::{chain, when};
+///
+/// let abs = when(|x: &i32| *x < 0, |x: i32| -x);
+///
+/// assert_eq!(abs(-3), 3);
+/// assert_eq!(abs(5), 5);
+///
+/// let normalize = chain(str::trim, when(|s: &&str| s.is_empty(), |_| "<empty>"));
+///
+/// assert_eq!(normalize("  "), "<empty>");
+/// assert_eq!(normalize(" a "), "a");
+/// ```
+///
+/// See also:
+/// - [`unless`]
+/// - [`if_else`]
+///
+/// [`unless`]: crate::unless
+/// [`if_else`]: crate::if_else
+#[inline]
+pub fn when<A, P, F>(pred: P, f: F) -> impl Fn(A) -> A
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> A,
+{
+    move |a: A| if pred(&a) { f(a) } else { a }
+}
+
+/// Apply function (which can be called only once) only when predicate holds.
+///
+/// See [when](self::when) for documentation.
+#[inline]
+pub fn when_once<A, P, F>(pred: P, f: F) -> impl FnOnce(A) -> A
+where
+    P: FnOnce(&A) -> bool,
+    F: FnOnce(A) -> A,
+{
+    move |a: A| if pred(&a) { f(a) } else { a }
+}
+
+/// Apply function (which can be called only by unique reference) only when
+/// predicate holds.
+///
+/// See [when](self::when) for documentation.
+#[inline]
+pub fn when_mut<A, P, F>(mut pred: P, mut f: F) -> impl FnMut(A) -> A
+where
+    P: FnMut(&A) -> bool,
+    F: FnMut(A) -> A,
+{
+    move |a: A| if pred(&a) { f(a) } else { a }
+}
+
+/// Apply function only when predicate does **not** hold.
+///
+/// Takes predicate `pred` and function `f` and returns
+/// `|a: A| if pred(&a) { a } else { f(a) }`.
+///
+/// # Examples
+/// ```
+/// use fntools::unless;
+///
+/// let with_slash = unless(|s: &String| s.ends_with('/'), |s: String| s + "/");
+///
+/// assert_eq!(with_slash(String::from("a/b")), "a/b/");
+/// assert_eq!(with_slash(String::from("a/b/")), "a/b/");
+/// ```
+///
+/// See also:
+/// - [`when`]
+///
+/// [`when`]: crate::when
+#[inline]
+pub fn unless<A, P, F>(pred: P, f: F) -> impl Fn(A) -> A
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> A,
+{
+    move |a: A| if pred(&a) { a } else { f(a) }
+}
+
+/// Apply function (which can be called only once) only when predicate does
+/// **not** hold.
+///
+/// See [unless](self::unless) for documentation.
+#[inline]
+pub fn unless_once<A, P, F>(pred: P, f: F) -> impl FnOnce(A) -> A
+where
+    P: FnOnce(&A) -> bool,
+    F: FnOnce(A) -> A,
+{
+    move |a: A| if pred(&a) { a } else { f(a) }
+}
+
+/// Apply function (which can be called only by unique reference) only when
+/// predicate does **not** hold.
+///
+/// See [unless](self::unless) for documentation.
+#[inline]
+pub fn unless_mut<A, P, F>(mut pred: P, mut f: F) -> impl FnMut(A) -> A
+where
+    P: FnMut(&A) -> bool,
+    F: FnMut(A) -> A,
+{
+    move |a: A| if pred(&a) { a } else { f(a) }
+}
+
+/// Apply one of two functions depending on predicate.
+///
+/// Takes predicate `pred` and functions `f`, `g` and returns
+/// `|a: A| if pred(&a) { f(a) } else { g(a) }`.
+///
+/// # Examples
+/// ```
+/// use fntools::if_else;
+///
+/// let collatz = if_else(|n: &u64| n % 2 == 0, |n: u64| n / 2, |n: u64| 3 * n + 1);
+///
+/// assert_eq!(collatz(6), 3);
+/// assert_eq!(collatz(3), 10);
+/// ```
+///
+/// See also:
+/// - [`when`]
+/// - [`cond!`] (for more than two branches)
+///
+/// [`when`]: crate::when
+/// [`cond!`]: crate::cond
+#[inline]
+pub fn if_else<A, B, P, F, G>(pred: P, f: F, g: G) -> impl Fn(A) -> B
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+    G: Fn(A) -> B,
+{
+    move |a: A| if pred(&a) { f(a) } else { g(a) }
+}
+
+/// Apply one of two functions (which can be called only once) depending on
+/// predicate.
+///
+/// See [if_else](self::if_else) for documentation.
+#[inline]
+pub fn if_else_once<A, B, P, F, G>(pred: P, f: F, g: G) -> impl FnOnce(A) -> B
+where
+    P: FnOnce(&A) -> bool,
+    F: FnOnce(A) -> B,
+    G: FnOnce(A) -> B,
+{
+    move |a: A| if pred(&a) { f(a) } else { g(a) }
+}
+
+/// Apply one of two functions (which can be called only by unique reference)
+/// depending on predicate.
+///
+/// See [if_else](self::if_else) for documentation.
+#[inline]
+pub fn if_else_mut<A, B, P, F, G>(mut pred: P, mut f: F, mut g: G) -> impl FnMut(A) -> B
+where
+    P: FnMut(&A) -> bool,
+    F: FnMut(A) -> B,
+    G: FnMut(A) -> B,
+{
+    move |a: A| if pred(&a) { f(a) } else { g(a) }
+}
+
+/// Apply function only when predicate holds, returning `None` otherwise.
+///
+/// Takes predicate `pred` and function `f` and returns
+/// `|a: A| if pred(&a) { Some(f(a)) } else { None }`.
+///
+/// # Examples
+/// ```
+/// use fntools::guard;
+///
+/// let checked_recip = guard(|x: &f64| *x != 0.0, |x: f64| 1.0 / x);
+///
+/// assert_eq!(checked_recip(4.0), Some(0.25));
+/// assert_eq!(checked_recip(0.0), None);
+///
+/// let v: Vec<_> = vec![2.0, 0.0, 0.5]
+///     .into_iter()
+///     .filter_map(checked_recip)
+///     .collect();
+/// assert_eq!(v, [0.5, 2.0]);
+/// ```
+#[inline]
+pub fn guard<A, B, P, F>(pred: P, f: F) -> impl Fn(A) -> Option<B>
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+{
+    move |a: A| if pred(&a) { Some(f(a)) } else { None }
+}
+
+/// Apply function (which can be called only once) only when predicate holds,
+/// returning `None` otherwise.
+///
+/// See [guard](self::guard) for documentation.
+#[inline]
+pub fn guard_once<A, B, P, F>(pred: P, f: F) -> impl FnOnce(A) -> Option<B>
+where
+    P: FnOnce(&A) -> bool,
+    F: FnOnce(A) -> B,
+{
+    move |a: A| if pred(&a) { Some(f(a)) } else { None }
+}
+
+/// Apply function (which can be called only by unique reference) only when
+/// predicate holds, returning `None` otherwise.
+///
+/// See [guard](self::guard) for documentation.
+#[inline]
+pub fn guard_mut<A, B, P, F>(mut pred: P, mut f: F) -> impl FnMut(A) -> Option<B>
+where
+    P: FnMut(&A) -> bool,
+    F: FnMut(A) -> B,
+{
+    move |a: A| if pred(&a) { Some(f(a)) } else { None }
+}
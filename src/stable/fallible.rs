@@ -0,0 +1,90 @@
+use crate::sealed::Sealed;
+use std::convert::Infallible;
+
+/// Type which can short-circuit like the `?` operator (e.g.: `Result`,
+/// `Option`).
+///
+/// This is a stable analog of the (unstable) `std::ops::Try` trait, used by
+/// [`try_chain`] and friends.
+///
+/// ## Examples
+/// ```
+/// use fntools::fallible::Fallible;
+///
+/// assert_eq!(Ok::<_, ()>(1).branch(), Ok(1));
+/// assert_eq!(Some(1).branch(), Ok(1));
+/// assert_eq!(None::<i32>.branch(), Err(None));
+/// ```
+///
+/// [`try_chain`]: crate::try_chain
+pub trait Fallible: Sealed {
+    /// Type of the value on success (e.g.: `T` for `Result<T, E>`)
+    type Output;
+
+    /// Type of the short-circuited value (e.g.: `Result<Infallible, E>` for
+    /// `Result<T, E>`)
+    type Residual;
+
+    /// Either continue with the success value or short-circuit.
+    fn branch(self) -> Result<Self::Output, Self::Residual>;
+}
+
+/// Type which can be constructed from a short-circuited [`Fallible`] value.
+///
+/// Just like `?`, this converts errors with `From`.
+///
+/// ## Examples
+/// ```
+/// use fntools::fallible::{Fallible, FromResidual};
+///
+/// let residual = Err::<(), u8>(1).branch().unwrap_err();
+/// assert_eq!(Result::<(), u32>::from_residual(residual), Err(1u32));
+/// ```
+pub trait FromResidual<R>: Sealed {
+    /// Construct `Self` from short-circuited value.
+    fn from_residual(residual: R) -> Self;
+}
+
+impl<T, E> Fallible for Result<T, E> {
+    type Output = T;
+    type Residual = Result<Infallible, E>;
+
+    #[inline]
+    fn branch(self) -> Result<Self::Output, Self::Residual> {
+        match self {
+            Ok(t) => Ok(t),
+            Err(e) => Err(Err(e)),
+        }
+    }
+}
+
+impl<T> Fallible for Option<T> {
+    type Output = T;
+    type Residual = Option<Infallible>;
+
+    #[inline]
+    fn branch(self) -> Result<Self::Output, Self::Residual> {
+        match self {
+            Some(t) => Ok(t),
+            None => Err(None),
+        }
+    }
+}
+
+impl<T, E, F> FromResidual<Result<Infallible, E>> for Result<T, F>
+where
+    F: From<E>,
+{
+    #[inline]
+    fn from_residual(residual: Result<Infallible, E>) -> Self {
+        match residual {
+            Ok(never) => match never {},
+            Err(e) => Err(From::from(e)),
+        }
+    }
+}
+
+impl<T> FromResidual<Option<Infallible>> for Option<T> {
+    #[inline]
+    fn from_residual(_: Option<Infallible>) -> Self { None }
+}
@@ -0,0 +1,113 @@
+use std::hash::Hash;
+
+use crate::memo::{HashCache, Memoize};
+
+/// Fixed-point combinator, makes recursive closures possible.
+///
+/// Takes function `f: (recur, A) -> R` and returns function `A -> R` which
+/// calls `f` passing itself as `recur`.
+///
+/// ## Examples
+/// ```
+/// use fntools::fix;
+///
+/// let factorial = fix(|recur, n: u64| if n == 0 { 1 } else { n * recur(n - 1) });
+///
+/// assert_eq!(factorial(0), 1);
+/// assert_eq!(factorial(5), 120);
+/// ```
+///
+/// Use tuple as the argument for recursion on many arguments:
+/// ```
+/// use fntools::fix;
+///
+/// let gcd = fix(|recur, (a, b): (u32, u32)| if b == 0 { a } else { recur((b, a % b)) });
+///
+/// assert_eq!(gcd((12, 18)), 6);
+/// ```
+///
+/// See also:
+/// - memoized version of this function: [`memo_fix`]
+/// - [`unstable::fix`] (which supports many arguments)
+///
+/// [`memo_fix`]: crate::memo_fix
+/// [`unstable::fix`]: crate::unstable::fix
+#[inline]
+pub fn fix<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    move |a: A| fix_call(&f, a)
+}
+
+fn fix_call<A, R, F>(f: &F, a: A) -> R
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+{
+    f(&|a| fix_call(f, a), a)
+}
+
+/// Memoized fixed-point combinator.
+///
+/// Like [`fix`], but caches results of all (including recursive) calls, so
+/// every argument is computed at most once. Useful for dynamic programming.
+///
+/// ## Examples
+/// ```
+/// use fntools::memo_fix;
+///
+/// let fib = memo_fix(|recur, n: u64| {
+///     if n < 2 {
+///         n
+///     } else {
+///         recur(n - 1) + recur(n - 2)
+///     }
+/// });
+///
+/// assert_eq!(fib(90), 2_880_067_194_370_816_120);
+/// ```
+///
+/// ```
+/// use fntools::memo_fix;
+///
+/// let (a, b) = (b"kitten", b"sitting");
+/// let distance = memo_fix(|recur, (i, j): (usize, usize)| match (i, j) {
+///     (0, j) => j,
+///     (i, 0) => i,
+///     (i, j) if a[i - 1] == b[j - 1] => recur((i - 1, j - 1)),
+///     (i, j) => {
+///         1 + recur((i - 1, j))
+///             .min(recur((i, j - 1)))
+///             .min(recur((i - 1, j - 1)))
+///     },
+/// });
+///
+/// assert_eq!(distance((a.len(), b.len())), 3);
+/// ```
+///
+/// See also:
+/// - [`memoize`]
+/// - [`unstable::memo_fix`] (which supports many arguments)
+///
+/// [`fix`]: crate::fix
+/// [`memoize`]: crate::memoize
+/// [`unstable::memo_fix`]: crate::unstable::memo_fix
+#[inline]
+pub fn memo_fix<A, R, F>(f: F) -> impl Fn(A) -> R
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    let memo = Memoize::with_cache(f, HashCache::new());
+    move |a: A| memo_fix_call(&memo, a)
+}
+
+fn memo_fix_call<A, R, F>(memo: &Memoize<F, HashCache<A, R>>, a: A) -> R
+where
+    F: Fn(&dyn Fn(A) -> R, A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    memo.get_or_call(a, |f, a| f(&|a| memo_fix_call(memo, a), a))
+}
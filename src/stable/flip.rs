@@ -1,3 +1,5 @@
+use crate::tuple::flip::FlipTuple;
+
 /// Flip function arguments.
 ///
 /// # Example
@@ -38,3 +40,113 @@ where
 {
     move |b: B, a: A| f(a, b)
 }
+
+/// Flip function output.
+///
+/// Takes function `f: A -> (X, Y, ..)` and returns `|a: A| f(a).flip()`,
+/// i.e. function `A -> (.., Y, X)`.
+///
+/// # Example
+/// ```
+/// use fntools::flip_output;
+///
+/// let div_rem = |(a, b): (u32, u32)| (a / b, a % b);
+/// let rem_div = flip_output(div_rem);
+///
+/// assert_eq!(rem_div((17, 5)), (2, 3));
+/// ```
+///
+/// See also:
+/// - [`FlipTuple`]
+/// - [`flip_both`]
+///
+/// [`FlipTuple`]: crate::tuple::flip::FlipTuple
+/// [`flip_both`]: crate::flip_both
+#[inline]
+pub fn flip_output<A, R, F>(f: F) -> impl Fn(A) -> R::Res
+where
+    F: Fn(A) -> R,
+    R: FlipTuple,
+{
+    move |a: A| f(a).flip()
+}
+
+/// Flip function (which can be called only once) output.
+///
+/// See [flip_output](self::flip_output) for documentation.
+#[inline]
+pub fn flip_output_once<A, R, F>(f: F) -> impl FnOnce(A) -> R::Res
+where
+    F: FnOnce(A) -> R,
+    R: FlipTuple,
+{
+    move |a: A| f(a).flip()
+}
+
+/// Flip function (which can be called only by unique reference) output.
+///
+/// See [flip_output](self::flip_output) for documentation.
+#[inline]
+pub fn flip_output_mut<A, R, F>(mut f: F) -> impl FnMut(A) -> R::Res
+where
+    F: FnMut(A) -> R,
+    R: FlipTuple,
+{
+    move |a: A| f(a).flip()
+}
+
+/// Flip both function arguments and output.
+///
+/// Takes function `f: A, B -> (X, Y, ..)` and returns
+/// `|b: B, a: A| f(a, b).flip()`.
+///
+/// # Example
+/// ```
+/// use fntools::flip_both;
+///
+/// let div_rem = |a: u32, b: u32| (a / b, a % b);
+/// let rem_div_flipped = flip_both(div_rem);
+///
+/// assert_eq!(rem_div_flipped(5, 17), (2, 3));
+/// ```
+///
+/// See also:
+/// - [`flip`]
+/// - [`flip_output`]
+///
+/// [`flip`]: crate::flip
+/// [`flip_output`]: crate::flip_output
+#[inline]
+pub fn flip_both<A, B, R, F>(f: F) -> impl Fn(B, A) -> R::Res
+where
+    F: Fn(A, B) -> R,
+    R: FlipTuple,
+{
+    move |b: B, a: A| f(a, b).flip()
+}
+
+/// Flip both arguments and output of function (which can be called only
+/// once).
+///
+/// See [flip_both](self::flip_both) for documentation.
+#[inline]
+pub fn flip_both_once<A, B, R, F>(f: F) -> impl FnOnce(B, A) -> R::Res
+where
+    F: FnOnce(A, B) -> R,
+    R: FlipTuple,
+{
+    move |b: B, a: A| f(a, b).flip()
+}
+
+/// Flip both arguments and output of function (which can be called only by
+/// unique reference).
+///
+/// See [flip_both](self::flip_both) for documentation.
+#[inline]
+pub fn flip_both_mut<A, B, R, F>(mut f: F) -> impl FnMut(B, A) -> R::Res
+where
+    F: FnMut(A, B) -> R,
+    R: FlipTuple,
+{
+    move |b: B, a: A| f(a, b).flip()
+}
@@ -0,0 +1,221 @@
+use std::iter::FusedIterator;
+
+/// Apply function `n` times.
+///
+/// Takes function `f: T -> T` and returns `fⁿ = |x: T| f(f(..f(x)..))` (`f`
+/// applied `n` times). `f⁰` is identity.
+///
+/// ## Examples
+/// ```
+/// use fntools::iterate;
+///
+/// let double = |x: u32| x * 2;
+/// let times_1024 = iterate(double, 10);
+///
+/// assert_eq!(times_1024(3), 3072);
+/// assert_eq!(iterate(double, 0)(3), 3);
+/// ```
+///
+/// See also:
+/// - [`iterate_until`]
+/// - [`orbit`]
+/// - [`FnExt::power`]
+///
+/// [`iterate_until`]: crate::iterate_until
+/// [`orbit`]: crate::orbit
+/// [`FnExt::power`]: crate::unstable::FnExt::power
+#[inline]
+pub fn iterate<T, F>(f: F, n: usize) -> impl Fn(T) -> T
+where
+    F: Fn(T) -> T,
+{
+    move |x: T| (0..n).fold(x, |x, _| f(x))
+}
+
+/// Apply function which can be called only by unique reference `n` times.
+///
+/// See [iterate](self::iterate) for documentation.
+#[inline]
+pub fn iterate_mut<T, F>(mut f: F, n: usize) -> impl FnMut(T) -> T
+where
+    F: FnMut(T) -> T,
+{
+    move |x: T| (0..n).fold(x, |x, _| f(x))
+}
+
+/// Apply function until predicate holds.
+///
+/// Takes function `f: T -> T` and predicate `pred: &T -> bool` and returns
+/// function which applies `f` to its argument until `pred` returns `true`
+/// (the argument itself is checked too).
+///
+/// **Note**: if `pred` never holds, the returned function never returns.
+///
+/// ## Examples
+/// ```
+/// use fntools::iterate_until;
+///
+/// let collatz = |n: u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+/// let steps = iterate_until(
+///     |(n, steps): (u64, u32)| (collatz(n), steps + 1),
+///     |&(n, _)| n == 1,
+/// );
+///
+/// assert_eq!(steps((27, 0)), (1, 111));
+/// assert_eq!(steps((1, 0)), (1, 0));
+/// ```
+#[inline]
+pub fn iterate_until<T, F, P>(f: F, pred: P) -> impl Fn(T) -> T
+where
+    F: Fn(T) -> T,
+    P: Fn(&T) -> bool,
+{
+    move |mut x: T| {
+        while !pred(&x) {
+            x = f(x);
+        }
+        x
+    }
+}
+
+/// Apply function which can be called only by unique reference until
+/// predicate holds.
+///
+/// See [iterate_until](self::iterate_until) for documentation.
+#[inline]
+pub fn iterate_until_mut<T, F, P>(mut f: F, mut pred: P) -> impl FnMut(T) -> T
+where
+    F: FnMut(T) -> T,
+    P: FnMut(&T) -> bool,
+{
+    move |mut x: T| {
+        while !pred(&x) {
+            x = f(x);
+        }
+        x
+    }
+}
+
+/// Apply function until its output equals its input.
+///
+/// Returns function which finds fixed point `x = f(x)` by repeatedly applying
+/// `f` to the argument.
+///
+/// **Note**: if the sequence doesn't converge, the returned function never
+/// returns.
+///
+/// ## Examples
+/// ```
+/// use fntools::fixpoint;
+///
+/// let simplify = fixpoint(|s: String| s.replace("()", ""));
+///
+/// assert_eq!(simplify(String::from("(()(()))x(")), "x(");
+/// ```
+#[inline]
+pub fn fixpoint<T, F>(f: F) -> impl Fn(T) -> T
+where
+    F: Fn(T) -> T,
+    T: PartialEq + Clone,
+{
+    move |mut x: T| loop {
+        let next = f(x.clone());
+        if next == x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Apply function which can be called only by unique reference until its
+/// output equals its input.
+///
+/// See [fixpoint](self::fixpoint) for documentation.
+#[inline]
+pub fn fixpoint_mut<T, F>(mut f: F) -> impl FnMut(T) -> T
+where
+    F: FnMut(T) -> T,
+    T: PartialEq + Clone,
+{
+    move |mut x: T| loop {
+        let next = f(x.clone());
+        if next == x {
+            return x;
+        }
+        x = next;
+    }
+}
+
+/// Iterator over successive applications of the function (`x`, `f(x)`,
+/// `f(f(x))`, ...).
+///
+/// The iterator is infinite and lazy: `f` is called only when the next value
+/// is requested.
+///
+/// ## Examples
+/// ```
+/// use fntools::orbit;
+///
+/// let powers: Vec<_> = orbit(|x: u32| x * 3, 1).take(5).collect();
+/// assert_eq!(powers, [1, 3, 9, 27, 81]);
+///
+/// let collatz = |n: u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+/// let len = orbit(collatz, 6).take_while(|&n| n != 1).count();
+/// assert_eq!(len, 8);
+/// ```
+#[inline]
+pub fn orbit<T, F>(f: F, init: T) -> Orbit<T, F>
+where
+    F: FnMut(T) -> T,
+    T: Clone,
+{
+    Orbit {
+        f,
+        value: Some(init),
+        started: false,
+    }
+}
+
+/// Iterator over successive applications of the function.
+///
+/// For documentation see [`orbit`].
+///
+/// [`orbit`]: crate::orbit
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Debug, Clone)]
+pub struct Orbit<T, F> {
+    f: F,
+    value: Option<T>,
+    started: bool,
+}
+
+impl<T, F> Iterator for Orbit<T, F>
+where
+    F: FnMut(T) -> T,
+    T: Clone,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T> {
+        let value = self.value.take()?;
+        let value = if self.started {
+            (self.f)(value)
+        } else {
+            self.started = true;
+            value
+        };
+        self.value = Some(value.clone());
+        Some(value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) { (usize::MAX, None) }
+}
+
+impl<T, F> FusedIterator for Orbit<T, F>
+where
+    F: FnMut(T) -> T,
+    T: Clone,
+{
+}
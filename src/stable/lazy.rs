@@ -0,0 +1,336 @@
+use std::{
+    cell::{Cell, UnsafeCell},
+    fmt::{self, Debug, Formatter},
+    ops::Deref,
+    sync::{Mutex, Once},
+};
+
+/// Value which is computed by `F` on first access and then cached.
+///
+/// ## Examples
+/// ```
+/// use fntools::lazy::Lazy;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let value = Lazy::new(|| {
+///     calls.set(calls.get() + 1);
+///     6 * 7
+/// });
+///
+/// assert_eq!(calls.get(), 0);
+/// assert_eq!(*value.force(), 42);
+/// assert_eq!(*value + 1, 43); // `Deref` forces too
+/// assert_eq!(calls.get(), 1);
+/// ```
+///
+/// Combining thunks:
+/// ```
+/// use fntools::lazy::Lazy;
+///
+/// let name = Lazy::new(|| String::from("world"));
+/// let greeting = name.map(|name| format!("Hello, {}!", name));
+/// let both = greeting.zip(Lazy::new(|| 2));
+///
+/// assert_eq!(*both, (String::from("Hello, world!"), 2));
+/// ```
+///
+/// See also:
+/// - thread-safe version of this type: [`SyncLazy`]
+/// - [`FnExt::thunk`]
+///
+/// [`FnExt::thunk`]: crate::unstable::FnExt::thunk
+pub struct Lazy<T, F = fn() -> T> {
+    // Invariant: `value` is written only once, while `init` is still `Some`,
+    // and is never mutated through a shared reference after that.
+    value: UnsafeCell<Option<T>>,
+    init: Cell<Option<F>>,
+}
+
+/// Type-erased [`Lazy`].
+pub type Thunk<'a, T> = Lazy<T, Box<dyn FnOnce() -> T + 'a>>;
+
+impl<T, F> Lazy<T, F> {
+    /// Returns the value if it's already computed.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        // SAFETY: once written, the value is never mutated through `&self`.
+        unsafe { (*self.value.get()).as_ref() }
+    }
+}
+
+impl<T, F> Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Creates value which will be computed by `f` on first access.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Lazy {
+            value: UnsafeCell::new(None),
+            init: Cell::new(Some(f)),
+        }
+    }
+
+    /// Computes the value (if it isn't computed yet) and returns reference to
+    /// it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the computation previously panicked or if it tries to force
+    /// the value itself.
+    #[inline]
+    pub fn force(&self) -> &T {
+        if let Some(value) = self.get() {
+            return value;
+        }
+
+        let value = match self.init.take() {
+            Some(f) => f(),
+            None => panic!("`Lazy` instance has previously been poisoned"),
+        };
+        // SAFETY: `init` was still there, so the value wasn't written yet and
+        // there are no references to it (reentrant `force` calls would have
+        // found `init` empty and panicked).
+        unsafe { *self.value.get() = Some(value) };
+        self.get().expect("value was just computed")
+    }
+
+    /// Computes the value (if it isn't computed yet) and returns it.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.force();
+        let Lazy { value, init: _ } = self;
+        value.into_inner().expect("value was just computed")
+    }
+
+    /// Lazily maps the value using `g`.
+    ///
+    /// If the value is already computed it's reused.
+    #[inline]
+    pub fn map<U, G>(self, g: G) -> Lazy<U, impl FnOnce() -> U>
+    where
+        G: FnOnce(T) -> U,
+    {
+        Lazy::new(move || g(self.into_value()))
+    }
+
+    /// Lazily combines two values into a tuple.
+    #[inline]
+    pub fn zip<U, G>(self, other: Lazy<U, G>) -> Lazy<(T, U), impl FnOnce() -> (T, U)>
+    where
+        G: FnOnce() -> U,
+    {
+        Lazy::new(move || (self.into_value(), other.into_value()))
+    }
+}
+
+impl<'a, T, F> Lazy<T, F>
+where
+    F: FnOnce() -> T + 'a,
+{
+    /// Erases type of the function, returning [`Thunk`].
+    #[inline]
+    pub fn boxed(self) -> Thunk<'a, T> {
+        let Lazy { value, init } = self;
+        Lazy {
+            value,
+            init: Cell::new(
+                init.into_inner()
+                    .map(|f| Box::new(f) as Box<dyn FnOnce() -> T + 'a>),
+            ),
+        }
+    }
+}
+
+impl<T, F> Deref for Lazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { self.force() }
+}
+
+impl<T, F> Debug for Lazy<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("Lazy").field(value).finish(),
+            None => f.write_str("Lazy(<unforced>)"),
+        }
+    }
+}
+
+/// Thread-safe value which is computed by `F` on first access and then cached.
+///
+/// The function is called at most once, even if the value is accessed from
+/// many threads at the same time.
+///
+/// ## Examples
+/// ```
+/// use fntools::lazy::SyncLazy;
+/// use std::{sync::Arc, thread};
+///
+/// let value = Arc::new(SyncLazy::new(|| (1..=10u64).product::<u64>()));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let value = Arc::clone(&value);
+///         thread::spawn(move || *value.force())
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 3_628_800);
+/// }
+/// ```
+///
+/// For documentation see [`Lazy`].
+pub struct SyncLazy<T, F = fn() -> T> {
+    // Invariant: `value` is written only once, inside of `once.call_once`, and
+    // is never mutated through a shared reference after that.
+    once: Once,
+    value: UnsafeCell<Option<T>>,
+    init: Mutex<Option<F>>,
+}
+
+// SAFETY: `value` is only written by `once.call_once` (which synchronizes
+// threads) and only read after `once` is completed. The value may be computed
+// and dropped on different threads, so `T: Send` is required too.
+unsafe impl<T, F> Sync for SyncLazy<T, F>
+where
+    T: Send + Sync,
+    F: Send,
+{
+}
+
+/// Type-erased [`SyncLazy`].
+pub type SyncThunk<'a, T> = SyncLazy<T, Box<dyn FnOnce() -> T + Send + 'a>>;
+
+impl<T, F> SyncLazy<T, F> {
+    /// Returns the value if it's already computed.
+    #[inline]
+    pub fn get(&self) -> Option<&T> {
+        if self.once.is_completed() {
+            // SAFETY: the value was written before `once` was completed and is
+            // never mutated through `&self` after that.
+            unsafe { (*self.value.get()).as_ref() }
+        } else {
+            None
+        }
+    }
+}
+
+impl<T, F> SyncLazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    /// Creates value which will be computed by `f` on first access.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        SyncLazy {
+            once: Once::new(),
+            value: UnsafeCell::new(None),
+            init: Mutex::new(Some(f)),
+        }
+    }
+
+    /// Computes the value (if it isn't computed yet) and returns reference to
+    /// it.
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the computation previously panicked.
+    #[inline]
+    pub fn force(&self) -> &T {
+        self.once.call_once(|| {
+            let init = self
+                .init
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .take();
+            let value = match init {
+                Some(f) => f(),
+                None => panic!("`SyncLazy` instance has previously been poisoned"),
+            };
+            // SAFETY: `call_once` runs this closure at most once and blocks
+            // all other callers until it's done, so there are no references
+            // to the value yet.
+            unsafe { *self.value.get() = Some(value) };
+        });
+        self.get().expect("value was just computed")
+    }
+
+    /// Computes the value (if it isn't computed yet) and returns it.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.force();
+        let SyncLazy { value, .. } = self;
+        value.into_inner().expect("value was just computed")
+    }
+
+    /// Lazily maps the value using `g`.
+    ///
+    /// If the value is already computed it's reused.
+    #[inline]
+    pub fn map<U, G>(self, g: G) -> SyncLazy<U, impl FnOnce() -> U>
+    where
+        G: FnOnce(T) -> U,
+    {
+        SyncLazy::new(move || g(self.into_value()))
+    }
+
+    /// Lazily combines two values into a tuple.
+    #[inline]
+    pub fn zip<U, G>(self, other: SyncLazy<U, G>) -> SyncLazy<(T, U), impl FnOnce() -> (T, U)>
+    where
+        G: FnOnce() -> U,
+    {
+        SyncLazy::new(move || (self.into_value(), other.into_value()))
+    }
+}
+
+impl<'a, T, F> SyncLazy<T, F>
+where
+    F: FnOnce() -> T + Send + 'a,
+{
+    /// Erases type of the function, returning [`SyncThunk`].
+    #[inline]
+    pub fn boxed(self) -> SyncThunk<'a, T> {
+        let SyncLazy { once, value, init } = self;
+        let init = init
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        SyncLazy {
+            once,
+            value,
+            init: Mutex::new(init.map(|f| Box::new(f) as Box<dyn FnOnce() -> T + Send + 'a>)),
+        }
+    }
+}
+
+impl<T, F> Deref for SyncLazy<T, F>
+where
+    F: FnOnce() -> T,
+{
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { self.force() }
+}
+
+impl<T, F> Debug for SyncLazy<T, F>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.get() {
+            Some(value) => f.debug_tuple("SyncLazy").field(value).finish(),
+            None => f.write_str("SyncLazy(<unforced>)"),
+        }
+    }
+}
@@ -0,0 +1,613 @@
+use crate::tuple::{
+    cartesian::TupleCartesian,
+    transpose::{TransposeOption, TransposeResult},
+};
+
+/// Lift function into `Option`s.
+///
+/// Takes function `f: A, B, .. -> R` and returns function
+/// `Option<A>, Option<B>, .. -> Option<R>` which calls `f` only if all
+/// arguments are `Some`. This version is for functions of 1 argument, see
+/// also [`lift_option2`], [`lift_option3`] and [`lift_option4`].
+///
+/// # Examples
+/// ```
+/// use fntools::lift_option;
+///
+/// let fun = lift_option(|a: i32| a * 2);
+///
+/// assert_eq!(fun(Some(2)), Some(4));
+/// assert_eq!(fun(None), None);
+/// ```
+///
+/// See also:
+/// - [`unstable::lift_option`]
+///
+/// [`unstable::lift_option`]: crate::unstable::lift_option
+/// [`lift_option2`]: crate::lift_option2
+/// [`lift_option3`]: crate::lift_option3
+/// [`lift_option4`]: crate::lift_option4
+#[inline]
+pub fn lift_option<A, R, F>(f: F) -> impl Fn(Option<A>) -> Option<R>
+where
+    F: Fn(A) -> R,
+{
+    move |a: Option<A>| a.map(&f)
+}
+
+/// Lift function into `Option`s, for functions which can be called only once.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option_once<A, R, F>(f: F) -> impl FnOnce(Option<A>) -> Option<R>
+where
+    F: FnOnce(A) -> R,
+{
+    move |a: Option<A>| a.map(f)
+}
+
+/// Lift function into `Option`s, for functions which can be called only by
+/// unique reference.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option_mut<A, R, F>(mut f: F) -> impl FnMut(Option<A>) -> Option<R>
+where
+    F: FnMut(A) -> R,
+{
+    move |a: Option<A>| a.map(&mut f)
+}
+
+/// Lift function of 2 arguments into `Option`s.
+///
+/// # Examples
+/// ```
+/// use fntools::lift_option2;
+///
+/// let add = lift_option2(|a: i32, b: i32| a + b);
+///
+/// assert_eq!(add(Some(1), Some(2)), Some(3));
+/// assert_eq!(add(Some(1), None), None);
+/// ```
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option2<A, B, R, F>(f: F) -> impl Fn(Option<A>, Option<B>) -> Option<R>
+where
+    F: Fn(A, B) -> R,
+{
+    move |a: Option<A>, b: Option<B>| (a, b).transpose_option().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 2 arguments into `Option`s, for functions which can be
+/// called only once.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option2_once<A, B, R, F>(f: F) -> impl FnOnce(Option<A>, Option<B>) -> Option<R>
+where
+    F: FnOnce(A, B) -> R,
+{
+    move |a: Option<A>, b: Option<B>| (a, b).transpose_option().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 2 arguments into `Option`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option2_mut<A, B, R, F>(mut f: F) -> impl FnMut(Option<A>, Option<B>) -> Option<R>
+where
+    F: FnMut(A, B) -> R,
+{
+    move |a: Option<A>, b: Option<B>| (a, b).transpose_option().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 3 arguments into `Option`s.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option3<A, B, C, R, F>(f: F) -> impl Fn(Option<A>, Option<B>, Option<C>) -> Option<R>
+where
+    F: Fn(A, B, C) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>| {
+        (a, b, c).transpose_option().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 3 arguments into `Option`s, for functions which can be
+/// called only once.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option3_once<A, B, C, R, F>(
+    f: F,
+) -> impl FnOnce(Option<A>, Option<B>, Option<C>) -> Option<R>
+where
+    F: FnOnce(A, B, C) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>| {
+        (a, b, c).transpose_option().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 3 arguments into `Option`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option3_mut<A, B, C, R, F>(
+    mut f: F,
+) -> impl FnMut(Option<A>, Option<B>, Option<C>) -> Option<R>
+where
+    F: FnMut(A, B, C) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>| {
+        (a, b, c).transpose_option().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 4 arguments into `Option`s.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option4<A, B, C, D, R, F>(
+    f: F,
+) -> impl Fn(Option<A>, Option<B>, Option<C>, Option<D>) -> Option<R>
+where
+    F: Fn(A, B, C, D) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>, d: Option<D>| {
+        (a, b, c, d)
+            .transpose_option()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function of 4 arguments into `Option`s, for functions which can be
+/// called only once.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option4_once<A, B, C, D, R, F>(
+    f: F,
+) -> impl FnOnce(Option<A>, Option<B>, Option<C>, Option<D>) -> Option<R>
+where
+    F: FnOnce(A, B, C, D) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>, d: Option<D>| {
+        (a, b, c, d)
+            .transpose_option()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function of 4 arguments into `Option`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_option](self::lift_option) for documentation.
+#[inline]
+pub fn lift_option4_mut<A, B, C, D, R, F>(
+    mut f: F,
+) -> impl FnMut(Option<A>, Option<B>, Option<C>, Option<D>) -> Option<R>
+where
+    F: FnMut(A, B, C, D) -> R,
+{
+    move |a: Option<A>, b: Option<B>, c: Option<C>, d: Option<D>| {
+        (a, b, c, d)
+            .transpose_option()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function into `Result`s.
+///
+/// Takes function `f: A, B, .. -> R` and returns function
+/// `Result<A, E>, Result<B, E>, .. -> Result<R, E>` which calls `f` only if
+/// all arguments are `Ok`, otherwise returns the first error. This version
+/// is for functions of 1 argument, see also [`lift_result2`],
+/// [`lift_result3`] and [`lift_result4`].
+///
+/// # Examples
+/// ```
+/// use fntools::lift_result;
+///
+/// let fun = lift_result(|a: i32| a * 2);
+///
+/// assert_eq!(fun(Ok::<_, ()>(2)), Ok(4));
+/// assert_eq!(fun(Err(())), Err(()));
+/// ```
+///
+/// See also:
+/// - [`unstable::lift_result`]
+///
+/// [`unstable::lift_result`]: crate::unstable::lift_result
+/// [`lift_result2`]: crate::lift_result2
+/// [`lift_result3`]: crate::lift_result3
+/// [`lift_result4`]: crate::lift_result4
+#[inline]
+pub fn lift_result<A, E, R, F>(f: F) -> impl Fn(Result<A, E>) -> Result<R, E>
+where
+    F: Fn(A) -> R,
+{
+    move |a: Result<A, E>| a.map(&f)
+}
+
+/// Lift function into `Result`s, for functions which can be called only once.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result_once<A, E, R, F>(f: F) -> impl FnOnce(Result<A, E>) -> Result<R, E>
+where
+    F: FnOnce(A) -> R,
+{
+    move |a: Result<A, E>| a.map(f)
+}
+
+/// Lift function into `Result`s, for functions which can be called only by
+/// unique reference.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result_mut<A, E, R, F>(mut f: F) -> impl FnMut(Result<A, E>) -> Result<R, E>
+where
+    F: FnMut(A) -> R,
+{
+    move |a: Result<A, E>| a.map(&mut f)
+}
+
+/// Lift function of 2 arguments into `Result`s.
+///
+/// # Examples
+/// ```
+/// use fntools::lift_result2;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let add = lift_result2(|a: i32, b: i32| a + b);
+///
+/// assert_eq!(add(parse("1"), parse("2")), Ok(3));
+/// assert!(add(parse("1"), parse("two")).is_err());
+/// ```
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result2<A, B, E, R, F>(f: F) -> impl Fn(Result<A, E>, Result<B, E>) -> Result<R, E>
+where
+    F: Fn(A, B) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>| (a, b).transpose_result().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 2 arguments into `Result`s, for functions which can be
+/// called only once.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result2_once<A, B, E, R, F>(
+    f: F,
+) -> impl FnOnce(Result<A, E>, Result<B, E>) -> Result<R, E>
+where
+    F: FnOnce(A, B) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>| (a, b).transpose_result().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 2 arguments into `Result`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+pub fn lift_result2_mut<A, B, E, R, F>(
+    mut f: F,
+) -> impl FnMut(Result<A, E>, Result<B, E>) -> Result<R, E>
+where
+    F: FnMut(A, B) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>| (a, b).transpose_result().map(|(a, b)| f(a, b))
+}
+
+/// Lift function of 3 arguments into `Result`s.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result3<A, B, C, E, R, F>(
+    f: F,
+) -> impl Fn(Result<A, E>, Result<B, E>, Result<C, E>) -> Result<R, E>
+where
+    F: Fn(A, B, C) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>| {
+        (a, b, c).transpose_result().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 3 arguments into `Result`s, for functions which can be
+/// called only once.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result3_once<A, B, C, E, R, F>(
+    f: F,
+) -> impl FnOnce(Result<A, E>, Result<B, E>, Result<C, E>) -> Result<R, E>
+where
+    F: FnOnce(A, B, C) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>| {
+        (a, b, c).transpose_result().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 3 arguments into `Result`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result3_mut<A, B, C, E, R, F>(
+    mut f: F,
+) -> impl FnMut(Result<A, E>, Result<B, E>, Result<C, E>) -> Result<R, E>
+where
+    F: FnMut(A, B, C) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>| {
+        (a, b, c).transpose_result().map(|(a, b, c)| f(a, b, c))
+    }
+}
+
+/// Lift function of 4 arguments into `Result`s.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result4<A, B, C, D, E, R, F>(
+    f: F,
+) -> impl Fn(Result<A, E>, Result<B, E>, Result<C, E>, Result<D, E>) -> Result<R, E>
+where
+    F: Fn(A, B, C, D) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>, d: Result<D, E>| {
+        (a, b, c, d)
+            .transpose_result()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function of 4 arguments into `Result`s, for functions which can be
+/// called only once.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result4_once<A, B, C, D, E, R, F>(
+    f: F,
+) -> impl FnOnce(Result<A, E>, Result<B, E>, Result<C, E>, Result<D, E>) -> Result<R, E>
+where
+    F: FnOnce(A, B, C, D) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>, d: Result<D, E>| {
+        (a, b, c, d)
+            .transpose_result()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function of 4 arguments into `Result`s, for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_result](self::lift_result) for documentation.
+#[inline]
+#[allow(clippy::type_complexity)]
+pub fn lift_result4_mut<A, B, C, D, E, R, F>(
+    mut f: F,
+) -> impl FnMut(Result<A, E>, Result<B, E>, Result<C, E>, Result<D, E>) -> Result<R, E>
+where
+    F: FnMut(A, B, C, D) -> R,
+{
+    move |a: Result<A, E>, b: Result<B, E>, c: Result<C, E>, d: Result<D, E>| {
+        (a, b, c, d)
+            .transpose_result()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+    }
+}
+
+/// Lift function into iterables (cartesian product).
+///
+/// Takes function `f: A, B, .. -> R` and returns function which takes
+/// iterables of `A`, `B`, .. and returns `Vec<R>` of results of calling `f`
+/// with every combination of the elements (see [`TupleCartesian`]). This
+/// version is for functions of 1 argument, see also [`lift_vec2`],
+/// [`lift_vec3`] and [`lift_vec4`].
+///
+/// # Examples
+/// ```
+/// use fntools::lift_vec;
+///
+/// let fun = lift_vec(|a: i32| a * 2);
+///
+/// assert_eq!(fun(vec![1, 2, 3]), [2, 4, 6]);
+/// ```
+///
+/// See also:
+/// - [`unstable::lift_vec`]
+///
+/// [`unstable::lift_vec`]: crate::unstable::lift_vec
+/// [`TupleCartesian`]: crate::tuple::cartesian::TupleCartesian
+/// [`lift_vec2`]: crate::lift_vec2
+/// [`lift_vec3`]: crate::lift_vec3
+/// [`lift_vec4`]: crate::lift_vec4
+#[inline]
+pub fn lift_vec<A, IA, R, F>(f: F) -> impl Fn(IA) -> Vec<R>
+where
+    F: Fn(A) -> R,
+    IA: IntoIterator<Item = A>,
+{
+    move |a: IA| a.into_iter().map(&f).collect()
+}
+
+/// Lift function into iterables (cartesian product), for functions which can be
+/// called only by unique reference.
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec_mut<A, IA, R, F>(mut f: F) -> impl FnMut(IA) -> Vec<R>
+where
+    F: FnMut(A) -> R,
+    IA: IntoIterator<Item = A>,
+{
+    move |a: IA| a.into_iter().map(&mut f).collect()
+}
+
+/// Lift function of 2 arguments into iterables (cartesian product).
+///
+/// # Examples
+/// ```
+/// use fntools::lift_vec2;
+///
+/// let add = lift_vec2(|a: i32, b: i32| a + b);
+///
+/// assert_eq!(add(vec![1, 2], vec![10, 20]), [11, 21, 12, 22]);
+/// ```
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec2<A, B, IA, IB, R, F>(f: F) -> impl Fn(IA, IB) -> Vec<R>
+where
+    F: Fn(A, B) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    A: Clone,
+    B: Clone,
+{
+    move |a: IA, b: IB| {
+        (a, b)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+}
+
+/// Lift function of 2 arguments into iterables (cartesian product), for
+/// functions which can be called only by unique reference.
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec2_mut<A, B, IA, IB, R, F>(mut f: F) -> impl FnMut(IA, IB) -> Vec<R>
+where
+    F: FnMut(A, B) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    A: Clone,
+    B: Clone,
+{
+    move |a: IA, b: IB| {
+        (a, b)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+}
+
+/// Lift function of 3 arguments into iterables (cartesian product).
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec3<A, B, C, IA, IB, IC, R, F>(f: F) -> impl Fn(IA, IB, IC) -> Vec<R>
+where
+    F: Fn(A, B, C) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    IC: IntoIterator<Item = C>,
+    A: Clone,
+    B: Clone,
+    C: Clone,
+{
+    move |a: IA, b: IB, c: IC| {
+        (a, b, c)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b, c)| f(a, b, c))
+            .collect()
+    }
+}
+
+/// Lift function of 3 arguments into iterables (cartesian product), for
+/// functions which can be called only by unique reference.
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec3_mut<A, B, C, IA, IB, IC, R, F>(mut f: F) -> impl FnMut(IA, IB, IC) -> Vec<R>
+where
+    F: FnMut(A, B, C) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    IC: IntoIterator<Item = C>,
+    A: Clone,
+    B: Clone,
+    C: Clone,
+{
+    move |a: IA, b: IB, c: IC| {
+        (a, b, c)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b, c)| f(a, b, c))
+            .collect()
+    }
+}
+
+/// Lift function of 4 arguments into iterables (cartesian product).
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec4<A, B, C, D, IA, IB, IC, ID, R, F>(f: F) -> impl Fn(IA, IB, IC, ID) -> Vec<R>
+where
+    F: Fn(A, B, C, D) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    IC: IntoIterator<Item = C>,
+    ID: IntoIterator<Item = D>,
+    A: Clone,
+    B: Clone,
+    C: Clone,
+    D: Clone,
+{
+    move |a: IA, b: IB, c: IC, d: ID| {
+        (a, b, c, d)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+            .collect()
+    }
+}
+
+/// Lift function of 4 arguments into iterables (cartesian product), for
+/// functions which can be called only by unique reference.
+///
+/// See [lift_vec](self::lift_vec) for documentation.
+#[inline]
+pub fn lift_vec4_mut<A, B, C, D, IA, IB, IC, ID, R, F>(
+    mut f: F,
+) -> impl FnMut(IA, IB, IC, ID) -> Vec<R>
+where
+    F: FnMut(A, B, C, D) -> R,
+    IA: IntoIterator<Item = A>,
+    IB: IntoIterator<Item = B>,
+    IC: IntoIterator<Item = C>,
+    ID: IntoIterator<Item = D>,
+    A: Clone,
+    B: Clone,
+    C: Clone,
+    D: Clone,
+{
+    move |a: IA, b: IB, c: IC, d: ID| {
+        (a, b, c, d)
+            .cartesian()
+            .into_iter()
+            .map(|(a, b, c, d)| f(a, b, c, d))
+            .collect()
+    }
+}
@@ -0,0 +1,343 @@
+/// Map each argument of the function with its own function.
+///
+/// Takes function `f: B1, B2 -> R` and tuple of functions
+/// `(g1: A1 -> B1, g2: A2 -> B2)` and returns
+/// `|a1: A1, a2: A2| f(g1(a1), g2(a2))` (like [Ramda's `useWith`]). This is
+/// the input-side counterpart of [`product`]. This version is for functions of
+/// 2 arguments, see also [`map_args3`], [`map_args4`], [`map_args5`] and
+/// [`map_args6`]. For mapping the only argument of a function use
+/// [`compose`].
+///
+/// # Examples
+/// ```
+/// use fntools::map_args2;
+///
+/// let same_len = map_args2(|a: usize, b: usize| a == b, (str::len, <[u8]>::len));
+///
+/// assert!(same_len("abc", &[1, 2, 3]));
+/// assert!(!same_len("abc", &[]));
+/// ```
+///
+/// See also:
+/// - [`unstable::map_args`]
+///
+/// [Ramda's `useWith`]: https://ramdajs.com/docs/#useWith
+/// [`product`]: crate::product
+/// [`compose`]: crate::compose
+/// [`unstable::map_args`]: crate::unstable::map_args
+/// [`map_args3`]: crate::map_args3
+/// [`map_args4`]: crate::map_args4
+/// [`map_args5`]: crate::map_args5
+/// [`map_args6`]: crate::map_args6
+#[inline]
+pub fn map_args2<A1, A2, B1, B2, R, F, G1, G2>(f: F, (g1, g2): (G1, G2)) -> impl Fn(A1, A2) -> R
+where
+    F: Fn(B1, B2) -> R,
+    G1: Fn(A1) -> B1,
+    G2: Fn(A2) -> B2,
+{
+    move |a1: A1, a2: A2| f(g1(a1), g2(a2))
+}
+
+/// Map each argument of the function with its own function, for functions
+/// which can be called only once.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args2_once<A1, A2, B1, B2, R, F, G1, G2>(
+    f: F,
+    (g1, g2): (G1, G2),
+) -> impl FnOnce(A1, A2) -> R
+where
+    F: FnOnce(B1, B2) -> R,
+    G1: FnOnce(A1) -> B1,
+    G2: FnOnce(A2) -> B2,
+{
+    move |a1: A1, a2: A2| f(g1(a1), g2(a2))
+}
+
+/// Map each argument of the function with its own function, for functions
+/// which can be called only by unique reference.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args2_mut<A1, A2, B1, B2, R, F, G1, G2>(
+    mut f: F,
+    (mut g1, mut g2): (G1, G2),
+) -> impl FnMut(A1, A2) -> R
+where
+    F: FnMut(B1, B2) -> R,
+    G1: FnMut(A1) -> B1,
+    G2: FnMut(A2) -> B2,
+{
+    move |a1: A1, a2: A2| f(g1(a1), g2(a2))
+}
+
+/// Map each argument of the function of 3 arguments with its own function.
+///
+/// # Examples
+/// ```
+/// use fntools::map_args3;
+///
+/// let volume = map_args3(
+///     |w: f64, h: f64, d: f64| w * h * d,
+///     (f64::from, f64::from, |cm: u32| f64::from(cm) / 100.0),
+/// );
+///
+/// assert_eq!(volume(2, 3, 50), 3.0);
+/// ```
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args3<A1, A2, A3, B1, B2, B3, R, F, G1, G2, G3>(
+    f: F,
+    (g1, g2, g3): (G1, G2, G3),
+) -> impl Fn(A1, A2, A3) -> R
+where
+    F: Fn(B1, B2, B3) -> R,
+    G1: Fn(A1) -> B1,
+    G2: Fn(A2) -> B2,
+    G3: Fn(A3) -> B3,
+{
+    move |a1: A1, a2: A2, a3: A3| f(g1(a1), g2(a2), g3(a3))
+}
+
+/// Map each argument of the function of 3 arguments with its own function, for
+/// functions which can be called only once.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args3_once<A1, A2, A3, B1, B2, B3, R, F, G1, G2, G3>(
+    f: F,
+    (g1, g2, g3): (G1, G2, G3),
+) -> impl FnOnce(A1, A2, A3) -> R
+where
+    F: FnOnce(B1, B2, B3) -> R,
+    G1: FnOnce(A1) -> B1,
+    G2: FnOnce(A2) -> B2,
+    G3: FnOnce(A3) -> B3,
+{
+    move |a1: A1, a2: A2, a3: A3| f(g1(a1), g2(a2), g3(a3))
+}
+
+/// Map each argument of the function of 3 arguments with its own function, for
+/// functions which can be called only by unique reference.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args3_mut<A1, A2, A3, B1, B2, B3, R, F, G1, G2, G3>(
+    mut f: F,
+    (mut g1, mut g2, mut g3): (G1, G2, G3),
+) -> impl FnMut(A1, A2, A3) -> R
+where
+    F: FnMut(B1, B2, B3) -> R,
+    G1: FnMut(A1) -> B1,
+    G2: FnMut(A2) -> B2,
+    G3: FnMut(A3) -> B3,
+{
+    move |a1: A1, a2: A2, a3: A3| f(g1(a1), g2(a2), g3(a3))
+}
+
+/// Map each argument of the function of 4 arguments with its own function.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args4<A1, A2, A3, A4, B1, B2, B3, B4, R, F, G1, G2, G3, G4>(
+    f: F,
+    (g1, g2, g3, g4): (G1, G2, G3, G4),
+) -> impl Fn(A1, A2, A3, A4) -> R
+where
+    F: Fn(B1, B2, B3, B4) -> R,
+    G1: Fn(A1) -> B1,
+    G2: Fn(A2) -> B2,
+    G3: Fn(A3) -> B3,
+    G4: Fn(A4) -> B4,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4| f(g1(a1), g2(a2), g3(a3), g4(a4))
+}
+
+/// Map each argument of the function of 4 arguments with its own function, for
+/// functions which can be called only once.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args4_once<A1, A2, A3, A4, B1, B2, B3, B4, R, F, G1, G2, G3, G4>(
+    f: F,
+    (g1, g2, g3, g4): (G1, G2, G3, G4),
+) -> impl FnOnce(A1, A2, A3, A4) -> R
+where
+    F: FnOnce(B1, B2, B3, B4) -> R,
+    G1: FnOnce(A1) -> B1,
+    G2: FnOnce(A2) -> B2,
+    G3: FnOnce(A3) -> B3,
+    G4: FnOnce(A4) -> B4,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4| f(g1(a1), g2(a2), g3(a3), g4(a4))
+}
+
+/// Map each argument of the function of 4 arguments with its own function, for
+/// functions which can be called only by unique reference.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args4_mut<A1, A2, A3, A4, B1, B2, B3, B4, R, F, G1, G2, G3, G4>(
+    mut f: F,
+    (mut g1, mut g2, mut g3, mut g4): (G1, G2, G3, G4),
+) -> impl FnMut(A1, A2, A3, A4) -> R
+where
+    F: FnMut(B1, B2, B3, B4) -> R,
+    G1: FnMut(A1) -> B1,
+    G2: FnMut(A2) -> B2,
+    G3: FnMut(A3) -> B3,
+    G4: FnMut(A4) -> B4,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4| f(g1(a1), g2(a2), g3(a3), g4(a4))
+}
+
+/// Map each argument of the function of 5 arguments with its own function.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args5<A1, A2, A3, A4, A5, B1, B2, B3, B4, B5, R, F, G1, G2, G3, G4, G5>(
+    f: F,
+    (g1, g2, g3, g4, g5): (G1, G2, G3, G4, G5),
+) -> impl Fn(A1, A2, A3, A4, A5) -> R
+where
+    F: Fn(B1, B2, B3, B4, B5) -> R,
+    G1: Fn(A1) -> B1,
+    G2: Fn(A2) -> B2,
+    G3: Fn(A3) -> B3,
+    G4: Fn(A4) -> B4,
+    G5: Fn(A5) -> B5,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5| f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5))
+}
+
+/// Map each argument of the function of 5 arguments with its own function, for
+/// functions which can be called only once.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args5_once<A1, A2, A3, A4, A5, B1, B2, B3, B4, B5, R, F, G1, G2, G3, G4, G5>(
+    f: F,
+    (g1, g2, g3, g4, g5): (G1, G2, G3, G4, G5),
+) -> impl FnOnce(A1, A2, A3, A4, A5) -> R
+where
+    F: FnOnce(B1, B2, B3, B4, B5) -> R,
+    G1: FnOnce(A1) -> B1,
+    G2: FnOnce(A2) -> B2,
+    G3: FnOnce(A3) -> B3,
+    G4: FnOnce(A4) -> B4,
+    G5: FnOnce(A5) -> B5,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5| f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5))
+}
+
+/// Map each argument of the function of 5 arguments with its own function, for
+/// functions which can be called only by unique reference.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args5_mut<A1, A2, A3, A4, A5, B1, B2, B3, B4, B5, R, F, G1, G2, G3, G4, G5>(
+    mut f: F,
+    (mut g1, mut g2, mut g3, mut g4, mut g5): (G1, G2, G3, G4, G5),
+) -> impl FnMut(A1, A2, A3, A4, A5) -> R
+where
+    F: FnMut(B1, B2, B3, B4, B5) -> R,
+    G1: FnMut(A1) -> B1,
+    G2: FnMut(A2) -> B2,
+    G3: FnMut(A3) -> B3,
+    G4: FnMut(A4) -> B4,
+    G5: FnMut(A5) -> B5,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5| f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5))
+}
+
+/// Map each argument of the function of 6 arguments with its own function.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args6<A1, A2, A3, A4, A5, A6, B1, B2, B3, B4, B5, B6, R, F, G1, G2, G3, G4, G5, G6>(
+    f: F,
+    (g1, g2, g3, g4, g5, g6): (G1, G2, G3, G4, G5, G6),
+) -> impl Fn(A1, A2, A3, A4, A5, A6) -> R
+where
+    F: Fn(B1, B2, B3, B4, B5, B6) -> R,
+    G1: Fn(A1) -> B1,
+    G2: Fn(A2) -> B2,
+    G3: Fn(A3) -> B3,
+    G4: Fn(A4) -> B4,
+    G5: Fn(A5) -> B5,
+    G6: Fn(A6) -> B6,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6| {
+        f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5), g6(a6))
+    }
+}
+
+/// Map each argument of the function of 6 arguments with its own function, for
+/// functions which can be called only once.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args6_once<
+    A1,
+    A2,
+    A3,
+    A4,
+    A5,
+    A6,
+    B1,
+    B2,
+    B3,
+    B4,
+    B5,
+    B6,
+    R,
+    F,
+    G1,
+    G2,
+    G3,
+    G4,
+    G5,
+    G6,
+>(
+    f: F,
+    (g1, g2, g3, g4, g5, g6): (G1, G2, G3, G4, G5, G6),
+) -> impl FnOnce(A1, A2, A3, A4, A5, A6) -> R
+where
+    F: FnOnce(B1, B2, B3, B4, B5, B6) -> R,
+    G1: FnOnce(A1) -> B1,
+    G2: FnOnce(A2) -> B2,
+    G3: FnOnce(A3) -> B3,
+    G4: FnOnce(A4) -> B4,
+    G5: FnOnce(A5) -> B5,
+    G6: FnOnce(A6) -> B6,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6| {
+        f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5), g6(a6))
+    }
+}
+
+/// Map each argument of the function of 6 arguments with its own function, for
+/// functions which can be called only by unique reference.
+///
+/// See [map_args2](self::map_args2) for documentation.
+#[inline]
+pub fn map_args6_mut<A1, A2, A3, A4, A5, A6, B1, B2, B3, B4, B5, B6, R, F, G1, G2, G3, G4, G5, G6>(
+    mut f: F,
+    (mut g1, mut g2, mut g3, mut g4, mut g5, mut g6): (G1, G2, G3, G4, G5, G6),
+) -> impl FnMut(A1, A2, A3, A4, A5, A6) -> R
+where
+    F: FnMut(B1, B2, B3, B4, B5, B6) -> R,
+    G1: FnMut(A1) -> B1,
+    G2: FnMut(A2) -> B2,
+    G3: FnMut(A3) -> B3,
+    G4: FnMut(A4) -> B4,
+    G5: FnMut(A5) -> B5,
+    G6: FnMut(A6) -> B6,
+{
+    move |a1: A1, a2: A2, a3: A3, a4: A4, a5: A5, a6: A6| {
+        f(g1(a1), g2(a2), g3(a3), g4(a4), g5(a5), g6(a6))
+    }
+}
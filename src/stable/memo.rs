@@ -0,0 +1,553 @@
+use std::{
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    hash::Hash,
+    sync::Mutex,
+};
+
+/// Storage for memoized function results.
+///
+/// Implement this trait to plug your own cache into [`Memoize`] or
+/// [`SyncMemoize`].
+pub trait Cache {
+    /// Type of the keys (arguments of the memoized function).
+    type Key;
+
+    /// Type of the cached values (results of the memoized function).
+    type Value;
+
+    /// Returns cached value for the `key`, if any.
+    fn get(&mut self, key: &Self::Key) -> Option<&Self::Value>;
+
+    /// Caches `value` for the `key`.
+    fn insert(&mut self, key: Self::Key, value: Self::Value);
+
+    /// Removes cached value for the `key`, returning it.
+    fn remove(&mut self, key: &Self::Key) -> Option<Self::Value>;
+
+    /// Removes all cached values.
+    fn clear(&mut self);
+
+    /// Returns number of cached values.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no cached values.
+    #[inline]
+    fn is_empty(&self) -> bool { self.len() == 0 }
+}
+
+/// Unbounded cache backed by a `HashMap`.
+#[derive(Debug, Clone)]
+pub struct HashCache<K, V> {
+    map: HashMap<K, V>,
+}
+
+impl<K, V> HashCache<K, V> {
+    /// Creates new empty cache.
+    #[inline]
+    pub fn new() -> Self {
+        HashCache {
+            map: HashMap::new(),
+        }
+    }
+}
+
+impl<K, V> Default for HashCache<K, V> {
+    #[inline]
+    fn default() -> Self { Self::new() }
+}
+
+impl<K, V> Cache for HashCache<K, V>
+where
+    K: Hash + Eq,
+{
+    type Key = K;
+    type Value = V;
+
+    #[inline]
+    fn get(&mut self, key: &K) -> Option<&V> { self.map.get(key) }
+
+    #[inline]
+    fn insert(&mut self, key: K, value: V) { self.map.insert(key, value); }
+
+    #[inline]
+    fn remove(&mut self, key: &K) -> Option<V> { self.map.remove(key) }
+
+    #[inline]
+    fn clear(&mut self) { self.map.clear() }
+
+    #[inline]
+    fn len(&self) -> usize { self.map.len() }
+}
+
+/// Bounded cache which evicts the least recently used value when full.
+///
+/// ## Examples
+/// ```
+/// use fntools::memo::{Cache, LruCache};
+///
+/// let mut cache = LruCache::new(2);
+/// cache.insert(1, "one");
+/// cache.insert(2, "two");
+/// cache.get(&1); // `1` is now used more recently than `2`
+/// cache.insert(3, "three");
+///
+/// assert_eq!(cache.get(&1), Some(&"one"));
+/// assert_eq!(cache.get(&2), None);
+/// assert_eq!(cache.get(&3), Some(&"three"));
+/// ```
+#[derive(Debug, Clone)]
+pub struct LruCache<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    order: BTreeMap<u64, K>,
+}
+
+impl<K, V> LruCache<K, V> {
+    /// Creates new empty cache which holds at most `capacity` values.
+    #[inline]
+    pub fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+        }
+    }
+
+    /// Returns maximum number of values this cache can hold.
+    #[inline]
+    pub fn capacity(&self) -> usize { self.capacity }
+}
+
+impl<K, V> Cache for LruCache<K, V>
+where
+    K: Hash + Eq + Clone,
+{
+    type Key = K;
+    type Value = V;
+
+    fn get(&mut self, key: &K) -> Option<&V> {
+        let LruCache {
+            tick,
+            entries,
+            order,
+            ..
+        } = self;
+        let (value, used) = entries.get_mut(key)?;
+        let key = order
+            .remove(used)
+            .expect("`order` is in sync with `entries`");
+        *used = *tick;
+        order.insert(*tick, key);
+        *tick += 1;
+        Some(value)
+    }
+
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        if let Some((_, used)) = self.entries.remove(&key) {
+            self.order.remove(&used);
+        } else if self.entries.len() == self.capacity {
+            let oldest = *self.order.keys().next().expect("cache is full");
+            let evicted = self.order.remove(&oldest).expect("key was just found");
+            self.entries.remove(&evicted);
+        }
+
+        self.order.insert(self.tick, key.clone());
+        self.entries.insert(key, (value, self.tick));
+        self.tick += 1;
+    }
+
+    fn remove(&mut self, key: &K) -> Option<V> {
+        let (value, used) = self.entries.remove(key)?;
+        self.order.remove(&used);
+        Some(value)
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+        self.order.clear();
+    }
+
+    #[inline]
+    fn len(&self) -> usize { self.entries.len() }
+}
+
+/// Cache statistics of [`Memoize`]/[`SyncMemoize`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Number of calls answered from the cache
+    pub hits: u64,
+    /// Number of calls which had to call the inner function
+    pub misses: u64,
+}
+
+#[derive(Debug)]
+struct State<C> {
+    cache: C,
+    stats: Stats,
+}
+
+impl<C> State<C> {
+    fn new(cache: C) -> Self {
+        State {
+            cache,
+            stats: Stats::default(),
+        }
+    }
+
+    fn lookup(&mut self, key: &C::Key) -> Option<C::Value>
+    where
+        C: Cache,
+        C::Value: Clone,
+    {
+        let res = self.cache.get(key).cloned();
+        match res {
+            Some(_) => self.stats.hits += 1,
+            None => self.stats.misses += 1,
+        }
+        res
+    }
+}
+
+/// Memoize function.
+///
+/// Returns [`Memoize`] which caches results of `f` in an unbounded
+/// [`HashCache`], keyed by the argument. Use tuple as the argument to memoize
+/// functions of many arguments.
+///
+/// ## Examples
+/// ```
+/// use fntools::memoize;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let square = memoize(|x: u64| {
+///     calls.set(calls.get() + 1);
+///     x * x
+/// });
+///
+/// assert_eq!(square.get(4), 16);
+/// assert_eq!(square.get(4), 16);
+/// assert_eq!(calls.get(), 1);
+///
+/// let stats = square.stats();
+/// assert_eq!((stats.hits, stats.misses), (1, 1));
+///
+/// square.invalidate(&4);
+/// assert_eq!(square.get(4), 16);
+/// assert_eq!(calls.get(), 2);
+/// ```
+///
+/// See also:
+/// - bounded version of this function: [`memoize_lru`]
+/// - thread-safe version of this function: [`memoize_sync`]
+/// - [`unstable::memoize`] (which memoizes functions of many arguments)
+///
+/// [`memoize_lru`]: crate::memoize_lru
+/// [`memoize_sync`]: crate::memoize_sync
+/// [`unstable::memoize`]: crate::unstable::memoize
+#[inline]
+pub fn memoize<A, R, F>(f: F) -> Memoize<F, HashCache<A, R>>
+where
+    F: Fn(A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    Memoize::with_cache(f, HashCache::new())
+}
+
+/// Memoize function, keeping only `capacity` most recently used results.
+///
+/// ## Examples
+/// ```
+/// use fntools::memoize_lru;
+///
+/// let len = memoize_lru(|s: String| s.len(), 1);
+///
+/// assert_eq!(len.get(String::from("a")), 1);
+/// assert_eq!(len.get(String::from("bb")), 2);
+/// assert_eq!(len.get(String::from("a")), 1);
+/// assert_eq!(len.stats().misses, 3);
+/// ```
+///
+/// See [memoize](self::memoize) for documentation.
+#[inline]
+pub fn memoize_lru<A, R, F>(f: F, capacity: usize) -> Memoize<F, LruCache<A, R>>
+where
+    F: Fn(A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    Memoize::with_cache(f, LruCache::new(capacity))
+}
+
+/// Memoize function, sharing the cache between threads.
+///
+/// ## Examples
+/// ```
+/// use fntools::memoize_sync;
+/// use std::{sync::Arc, thread};
+///
+/// let square = Arc::new(memoize_sync(|x: u64| x * x));
+///
+/// let handles: Vec<_> = (0..4)
+///     .map(|_| {
+///         let square = Arc::clone(&square);
+///         thread::spawn(move || square.get(12))
+///     })
+///     .collect();
+///
+/// for handle in handles {
+///     assert_eq!(handle.join().unwrap(), 144);
+/// }
+/// ```
+///
+/// See [memoize](self::memoize) for documentation.
+#[inline]
+pub fn memoize_sync<A, R, F>(f: F) -> SyncMemoize<F, HashCache<A, R>>
+where
+    F: Fn(A) -> R,
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    SyncMemoize::with_cache(f, HashCache::new())
+}
+
+/// Memoized function `F` with cache `C`.
+///
+/// Uses interior mutability, so it can be called by shared reference, but
+/// can't be shared between threads (see [`SyncMemoize`] for that). The inner
+/// function is called without the cache being borrowed, so it can call the
+/// memoized function recursively.
+///
+/// For documentation see [`memoize`].
+///
+/// [`memoize`]: crate::memoize
+#[derive(Debug)]
+pub struct Memoize<F, C> {
+    f: F,
+    state: RefCell<State<C>>,
+}
+
+impl<F, C> Memoize<F, C> {
+    /// Creates memoized version of the function `f` which uses `cache`.
+    #[inline]
+    pub fn with_cache(f: F, cache: C) -> Self {
+        Memoize {
+            f,
+            state: RefCell::new(State::new(cache)),
+        }
+    }
+
+    /// Returns result of `f(arg)`, calling `f` only if the result isn't cached.
+    #[inline]
+    pub fn get(&self, arg: C::Key) -> C::Value
+    where
+        F: Fn(C::Key) -> C::Value,
+        C: Cache,
+        C::Key: Clone,
+        C::Value: Clone,
+    {
+        self.get_or_call(arg, |f, arg| f(arg))
+    }
+
+    /// Returns cache statistics.
+    #[inline]
+    pub fn stats(&self) -> Stats { self.state.borrow().stats }
+
+    /// Removes cached result for the `key`, returns `true` if there was one.
+    #[inline]
+    pub fn invalidate(&self, key: &C::Key) -> bool
+    where
+        C: Cache,
+    {
+        self.state.borrow_mut().cache.remove(key).is_some()
+    }
+
+    /// Removes all cached results and resets statistics.
+    #[inline]
+    pub fn clear(&self)
+    where
+        C: Cache,
+    {
+        let mut state = self.state.borrow_mut();
+        state.cache.clear();
+        state.stats = Stats::default();
+    }
+
+    /// Returns inner function and cache.
+    #[inline]
+    pub fn into_inner(self) -> (F, C) {
+        let Memoize { f, state } = self;
+        (f, state.into_inner().cache)
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+
+    pub(crate) fn get_or_call(
+        &self,
+        key: C::Key,
+        call: impl FnOnce(&F, C::Key) -> C::Value,
+    ) -> C::Value
+    where
+        C: Cache,
+        C::Key: Clone,
+        C::Value: Clone,
+    {
+        if let Some(value) = self.state.borrow_mut().lookup(&key) {
+            return value;
+        }
+
+        let value = call(&self.f, key.clone());
+        self.state.borrow_mut().cache.insert(key, value.clone());
+        value
+    }
+}
+
+/// Thread-safe memoized function `F` with cache `C`.
+///
+/// The cache is protected by a `Mutex` which isn't held while the inner
+/// function is called, so concurrent calls with the same argument may call
+/// the inner function more than once.
+///
+/// For documentation see [`memoize_sync`].
+///
+/// [`memoize_sync`]: crate::memoize_sync
+#[derive(Debug)]
+pub struct SyncMemoize<F, C> {
+    f: F,
+    state: Mutex<State<C>>,
+}
+
+impl<F, C> SyncMemoize<F, C> {
+    /// Creates thread-safe memoized version of the function `f` which uses
+    /// `cache`.
+    #[inline]
+    pub fn with_cache(f: F, cache: C) -> Self {
+        SyncMemoize {
+            f,
+            state: Mutex::new(State::new(cache)),
+        }
+    }
+
+    /// Returns result of `f(arg)`, calling `f` only if the result isn't cached.
+    #[inline]
+    pub fn get(&self, arg: C::Key) -> C::Value
+    where
+        F: Fn(C::Key) -> C::Value,
+        C: Cache,
+        C::Key: Clone,
+        C::Value: Clone,
+    {
+        self.get_or_call(arg, |f, arg| f(arg))
+    }
+
+    /// Returns cache statistics.
+    #[inline]
+    pub fn stats(&self) -> Stats { self.lock().stats }
+
+    /// Removes cached result for the `key`, returns `true` if there was one.
+    #[inline]
+    pub fn invalidate(&self, key: &C::Key) -> bool
+    where
+        C: Cache,
+    {
+        self.lock().cache.remove(key).is_some()
+    }
+
+    /// Removes all cached results and resets statistics.
+    #[inline]
+    pub fn clear(&self)
+    where
+        C: Cache,
+    {
+        let mut state = self.lock();
+        state.cache.clear();
+        state.stats = Stats::default();
+    }
+
+    /// Returns inner function and cache.
+    #[inline]
+    pub fn into_inner(self) -> (F, C) {
+        let SyncMemoize { f, state } = self;
+        let state = state
+            .into_inner()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        (f, state.cache)
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { &self.f }
+
+    pub(crate) fn get_or_call(
+        &self,
+        key: C::Key,
+        call: impl FnOnce(&F, C::Key) -> C::Value,
+    ) -> C::Value
+    where
+        C: Cache,
+        C::Key: Clone,
+        C::Value: Clone,
+    {
+        if let Some(value) = self.lock().lookup(&key) {
+            return value;
+        }
+
+        let value = call(&self.f, key.clone());
+        self.lock().cache.insert(key, value.clone());
+        value
+    }
+
+    // The cache stays consistent even if some thread panicked while holding the
+    // lock, so poisoning is ignored.
+    fn lock(&self) -> std::sync::MutexGuard<'_, State<C>> {
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memo::{Cache, LruCache};
+
+    #[test]
+    fn lru_evicts_least_recently_used() {
+        let mut cache = LruCache::new(3);
+        cache.insert(1, 'a');
+        cache.insert(2, 'b');
+        cache.insert(3, 'c');
+
+        // touch `1` and overwrite `2`, so `3` becomes the oldest
+        assert_eq!(cache.get(&1), Some(&'a'));
+        cache.insert(2, 'B');
+        cache.insert(4, 'd');
+
+        assert_eq!(cache.len(), 3);
+        assert_eq!(cache.get(&3), None);
+        assert_eq!(cache.get(&1), Some(&'a'));
+        assert_eq!(cache.get(&2), Some(&'B'));
+        assert_eq!(cache.get(&4), Some(&'d'));
+
+        assert_eq!(cache.remove(&1), Some('a'));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn lru_zero_capacity() {
+        let mut cache = LruCache::new(0);
+        cache.insert(1, 'a');
+
+        assert!(cache.is_empty());
+        assert_eq!(cache.get(&1), None);
+    }
+}
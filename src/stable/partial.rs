@@ -0,0 +1,305 @@
+/// Function `A -> B` which is defined only for some values of `A`.
+///
+/// ## Examples
+/// ```
+/// use fntools::partial::{partial, PartialFn};
+///
+/// let sqrt = partial(|x: &f64| *x >= 0.0, |x: f64| x.sqrt());
+/// let neg_sqrt = partial(|x: &f64| *x < 0.0, |x: f64| -(-x).sqrt());
+///
+/// assert!(sqrt.is_defined_at(&4.0));
+/// assert!(!sqrt.is_defined_at(&-4.0));
+/// assert_eq!(sqrt.apply_partial(4.0), Some(2.0));
+/// assert_eq!(sqrt.apply_partial(-4.0), None);
+///
+/// let total = sqrt.or_else(neg_sqrt);
+/// assert!(total.is_defined_at(&-4.0));
+/// assert_eq!(total.apply_partial(-4.0), Some(-2.0));
+/// ```
+pub trait PartialFn<A, B>: Sized {
+    /// Returns `true` if the function is defined at `a`.
+    fn is_defined_at(&self, a: &A) -> bool;
+
+    /// Apply the function to `a`, returning `a` back if the function isn't
+    /// defined at it.
+    fn try_apply(&self, a: A) -> Result<B, A>;
+
+    /// Apply the function to `a`, returning `None` if the function isn't
+    /// defined at it.
+    #[inline]
+    fn apply_partial(&self, a: A) -> Option<B> { self.try_apply(a).ok() }
+
+    /// Fall back to `other` where `self` isn't defined.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::partial::{partial, PartialFn};
+    ///
+    /// let small = partial(|n: &u32| *n < 10, |n: u32| format!("small {}", n));
+    /// let even = partial(|n: &u32| n % 2 == 0, |n: u32| format!("even {}", n));
+    /// let both = small.or_else(even);
+    ///
+    /// assert_eq!(both.apply_partial(4).as_deref(), Some("small 4"));
+    /// assert_eq!(both.apply_partial(12).as_deref(), Some("even 12"));
+    /// assert_eq!(both.apply_partial(13), None);
+    /// ```
+    #[inline]
+    fn or_else<G>(self, other: G) -> OrElse<Self, G>
+    where
+        G: PartialFn<A, B>,
+    {
+        OrElse { f: self, g: other }
+    }
+}
+
+/// Turn partial function into total function returning `Option`.
+///
+/// ## Examples
+/// ```
+/// use fntools::partial::{lift, partial};
+///
+/// let half = lift(partial(|n: &i32| n % 2 == 0, |n: i32| n / 2));
+/// let v: Vec<_> = (1..=6).filter_map(half).collect();
+///
+/// assert_eq!(v, [1, 2, 3]);
+/// ```
+#[inline]
+pub fn lift<A, B, F>(f: F) -> impl Fn(A) -> Option<B>
+where
+    F: PartialFn<A, B>,
+{
+    move |a: A| f.apply_partial(a)
+}
+
+/// Turn partial function into total function, using `default` where `f`
+/// isn't defined.
+///
+/// ## Examples
+/// ```
+/// use fntools::partial::{partial, unwrap_or};
+///
+/// let describe = unwrap_or(partial(|n: &i32| *n < 0, |_| "negative"), |_| {
+///     "non-negative"
+/// });
+///
+/// assert_eq!(describe(-1), "negative");
+/// assert_eq!(describe(1), "non-negative");
+/// ```
+#[inline]
+pub fn unwrap_or<A, B, F, D>(f: F, default: D) -> impl Fn(A) -> B
+where
+    F: PartialFn<A, B>,
+    D: Fn(A) -> B,
+{
+    move |a: A| f.try_apply(a).unwrap_or_else(&default)
+}
+
+/// Create partial function from predicate which defines its domain and total
+/// function.
+///
+/// `f` is called only with values for which `pred` holds.
+///
+/// ## Examples
+/// ```
+/// use fntools::partial::{partial, PartialFn};
+///
+/// let first = partial(|v: &Vec<i32>| !v.is_empty(), |v: Vec<i32>| v[0]);
+///
+/// assert_eq!(first.apply_partial(vec![1, 2]), Some(1));
+/// assert_eq!(first.try_apply(vec![]), Err(vec![]));
+/// ```
+#[inline]
+pub fn partial<A, B, P, F>(pred: P, f: F) -> Partial<P, F>
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+{
+    Partial { pred, f }
+}
+
+/// Create partial function from function returning `Option`.
+///
+/// Function is considered defined at `a` if it returns `Some(_)`, so
+/// [`is_defined_at`] and [`try_apply`] need to clone the argument (and
+/// `is_defined_at` calls the function).
+///
+/// ## Examples
+/// ```
+/// use fntools::partial::{partial_opt, PartialFn};
+///
+/// let parse = partial_opt(|s: &str| s.parse::<i32>().ok());
+/// let len = partial_opt(|s: &str| Some(s.len() as i32));
+///
+/// assert!(!parse.is_defined_at(&"x"));
+/// assert_eq!(parse.or_else(len).apply_partial("42"), Some(42));
+/// assert_eq!(parse.or_else(len).apply_partial("abc"), Some(3));
+/// ```
+///
+/// [`is_defined_at`]: crate::partial::PartialFn::is_defined_at
+/// [`try_apply`]: crate::partial::PartialFn::try_apply
+#[inline]
+pub fn partial_opt<A, B, F>(f: F) -> PartialOpt<F>
+where
+    A: Clone,
+    F: Fn(A) -> Option<B>,
+{
+    PartialOpt(f)
+}
+
+/// Partial function defined by predicate `P` and function `F`.
+///
+/// For documentation see [`partial`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Partial<P, F> {
+    pred: P,
+    f: F,
+}
+
+impl<P, F> Partial<P, F> {
+    /// Returns inner predicate and function.
+    #[inline]
+    pub fn into_inner(self) -> (P, F) {
+        let Partial { pred, f } = self;
+        (pred, f)
+    }
+}
+
+impl<A, B, P, F> PartialFn<A, B> for Partial<P, F>
+where
+    P: Fn(&A) -> bool,
+    F: Fn(A) -> B,
+{
+    #[inline]
+    fn is_defined_at(&self, a: &A) -> bool { (self.pred)(a) }
+
+    #[inline]
+    fn try_apply(&self, a: A) -> Result<B, A> {
+        if (self.pred)(&a) {
+            Ok((self.f)(a))
+        } else {
+            Err(a)
+        }
+    }
+}
+
+/// Partial function defined by function `F` returning `Option`.
+///
+/// For documentation see [`partial_opt`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct PartialOpt<F>(F);
+
+impl<F> PartialOpt<F> {
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let PartialOpt(f) = self;
+        f
+    }
+}
+
+impl<A, B, F> PartialFn<A, B> for PartialOpt<F>
+where
+    A: Clone,
+    F: Fn(A) -> Option<B>,
+{
+    #[inline]
+    fn is_defined_at(&self, a: &A) -> bool { (self.0)(a.clone()).is_some() }
+
+    #[inline]
+    fn try_apply(&self, a: A) -> Result<B, A> { (self.0)(a.clone()).ok_or(a) }
+
+    #[inline]
+    fn apply_partial(&self, a: A) -> Option<B> { (self.0)(a) }
+}
+
+/// Partial function `F` with fallback to partial function `G`.
+///
+/// For documentation see [`PartialFn::or_else`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct OrElse<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> OrElse<F, G> {
+    /// Returns inner partial functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let OrElse { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, B, F, G> PartialFn<A, B> for OrElse<F, G>
+where
+    F: PartialFn<A, B>,
+    G: PartialFn<A, B>,
+{
+    #[inline]
+    fn is_defined_at(&self, a: &A) -> bool { self.f.is_defined_at(a) || self.g.is_defined_at(a) }
+
+    #[inline]
+    fn try_apply(&self, a: A) -> Result<B, A> {
+        self.f.try_apply(a).or_else(|a| self.g.try_apply(a))
+    }
+}
+
+/// Call `f`, falling back to `g` (with the same argument) if `f` fails.
+///
+/// Takes functions `f: A -> Result<B, E1>` and `g: A -> Result<B, E2>` and
+/// returns `|a: A| f(a.clone()).or_else(|_| g(a))`. Error of `f` is
+/// discarded.
+///
+/// # Examples
+/// ```
+/// use fntools::fallback;
+///
+/// let parse = fallback(
+///     |s: &str| s.parse::<u8>(),
+///     |s: &str| u8::from_str_radix(s.trim_start_matches("0x"), 16),
+/// );
+///
+/// assert_eq!(parse("42"), Ok(42));
+/// assert_eq!(parse("0xff"), Ok(255));
+/// assert!(parse("zz").is_err());
+/// ```
+#[inline]
+pub fn fallback<A, B, E1, E2, F, G>(f: F, g: G) -> impl Fn(A) -> Result<B, E2>
+where
+    A: Clone,
+    F: Fn(A) -> Result<B, E1>,
+    G: Fn(A) -> Result<B, E2>,
+{
+    move |a: A| f(a.clone()).or_else(|_| g(a))
+}
+
+/// Call `f`, falling back to `g` (with the same argument) if `f` fails, for
+/// functions which can be called only once.
+///
+/// See [fallback](self::fallback) for documentation.
+#[inline]
+pub fn fallback_once<A, B, E1, E2, F, G>(f: F, g: G) -> impl FnOnce(A) -> Result<B, E2>
+where
+    A: Clone,
+    F: FnOnce(A) -> Result<B, E1>,
+    G: FnOnce(A) -> Result<B, E2>,
+{
+    move |a: A| f(a.clone()).or_else(|_| g(a))
+}
+
+/// Call `f`, falling back to `g` (with the same argument) if `f` fails, for
+/// functions which can be called only by unique reference.
+///
+/// See [fallback](self::fallback) for documentation.
+#[inline]
+pub fn fallback_mut<A, B, E1, E2, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> Result<B, E2>
+where
+    A: Clone,
+    F: FnMut(A) -> Result<B, E1>,
+    G: FnMut(A) -> Result<B, E2>,
+{
+    move |a: A| f(a.clone()).or_else(|_| g(a))
+}
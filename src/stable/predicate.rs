@@ -0,0 +1,211 @@
+/// Logical and of predicates (short-circuiting): `|x| p(x) && q(x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::and;
+///
+/// let even_positive = and(|x: &i32| x % 2 == 0, |x: &i32| *x > 0);
+///
+/// let v: Vec<_> = (-4..=4).filter(even_positive).collect();
+/// assert_eq!(v, [2, 4]);
+/// ```
+///
+/// See also:
+/// - [`unstable::and`] (which returns nameable type)
+///
+/// [`unstable::and`]: crate::unstable::and
+#[inline]
+pub fn and<T, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |x: &T| p(x) && q(x)
+}
+
+/// Logical or of predicates (short-circuiting): `|x| p(x) || q(x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::{not, or};
+///
+/// let odd_or_zero = or(not(|x: &i32| x % 2 == 0), |x: &i32| *x == 0);
+///
+/// let v: Vec<_> = (-4..=4).filter(odd_or_zero).collect();
+/// assert_eq!(v, [-3, -1, 0, 1, 3]);
+/// ```
+///
+/// See also:
+/// - [`unstable::or`] (which returns nameable type)
+///
+/// [`unstable::or`]: crate::unstable::or
+#[inline]
+pub fn or<T, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |x: &T| p(x) || q(x)
+}
+
+/// Logical negation of predicate: `|x| !p(x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::not;
+///
+/// let non_empty = not(|s: &&str| s.is_empty());
+///
+/// let v: Vec<_> = vec!["a", "", "b"].into_iter().filter(non_empty).collect();
+/// assert_eq!(v, ["a", "b"]);
+/// ```
+///
+/// See also:
+/// - [`unstable::not`] (which returns nameable type)
+///
+/// [`unstable::not`]: crate::unstable::not
+#[inline]
+pub fn not<T, P>(p: P) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    P: Fn(&T) -> bool,
+{
+    move |x: &T| !p(x)
+}
+
+/// Logical exclusive or of predicates: `|x| p(x) ^ q(x)`.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::xor;
+///
+/// let v: Vec<_> = (-4..=4)
+///     .filter(xor(|x: &i32| x % 2 == 0, |x: &i32| *x > 0))
+///     .collect();
+/// assert_eq!(v, [-4, -2, 0, 1, 3]);
+/// ```
+///
+/// See also:
+/// - [`unstable::xor`] (which returns nameable type)
+///
+/// [`unstable::xor`]: crate::unstable::xor
+#[inline]
+pub fn xor<T, P, Q>(p: P, q: Q) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    P: Fn(&T) -> bool,
+    Q: Fn(&T) -> bool,
+{
+    move |x: &T| p(x) ^ q(x)
+}
+
+/// Tuple of predicates (`Fn(&T) -> bool`) which can be tested all at once.
+///
+/// Implemented for tuples of arity 1 to 12.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::Predicates;
+///
+/// let preds = (|s: &str| s.len() > 2, |s: &str| s.starts_with('a'));
+///
+/// assert!(preds.all("abc"));
+/// assert!(!preds.all("bcd"));
+/// assert!(preds.any("bcd"));
+/// assert!(!preds.any("b"));
+/// ```
+pub trait Predicates<T: ?Sized> {
+    /// Returns `true` if **all** predicates hold for `value` (short-circuiting,
+    /// in order).
+    fn all(&self, value: &T) -> bool;
+
+    /// Returns `true` if **any** predicate holds for `value` (short-circuiting,
+    /// in order).
+    fn any(&self, value: &T) -> bool;
+}
+
+macro_rules! predicates_impl {
+    ($( $p:ident, )+) => {
+        impl<T: ?Sized, $( $p, )+> Predicates<T> for ($( $p, )+)
+        where
+            $( $p: Fn(&T) -> bool, )+
+        {
+            #[inline]
+            #[allow(non_snake_case)]
+            fn all(&self, value: &T) -> bool {
+                let ($( $p, )+) = self;
+                $( $p(value) )&&+
+            }
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn any(&self, value: &T) -> bool {
+                let ($( $p, )+) = self;
+                $( $p(value) )||+
+            }
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, # predicates_impl);
+
+/// Combine tuple of predicates into one which holds if **all** of them hold.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::all_of;
+///
+/// let valid_username = all_of((
+///     |s: &&str| !s.is_empty(),
+///     |s: &&str| s.len() <= 8,
+///     |s: &&str| s.chars().all(char::is_alphanumeric),
+/// ));
+///
+/// let names = ["alice", "", "bob_", "verylongname", "eve42"];
+/// let valid: Vec<_> = names.iter().copied().filter(valid_username).collect();
+/// assert_eq!(valid, ["alice", "eve42"]);
+/// ```
+///
+/// See also:
+/// - [`any_of`]
+/// - [`Predicates`]
+///
+/// [`any_of`]: crate::predicate::any_of
+/// [`Predicates`]: crate::predicate::Predicates
+#[inline]
+pub fn all_of<T, Ps>(predicates: Ps) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    move |value: &T| predicates.all(value)
+}
+
+/// Combine tuple of predicates into one which holds if **any** of them holds.
+///
+/// ## Examples
+/// ```
+/// use fntools::predicate::any_of;
+///
+/// let special = any_of((|x: &u32| *x == 0, |x: &u32| x.is_power_of_two()));
+///
+/// let v: Vec<_> = (0..10).filter(special).collect();
+/// assert_eq!(v, [0, 1, 2, 4, 8]);
+/// ```
+///
+/// See also:
+/// - [`all_of`]
+/// - [`Predicates`]
+///
+/// [`all_of`]: crate::predicate::all_of
+/// [`Predicates`]: crate::predicate::Predicates
+#[inline]
+pub fn any_of<T, Ps>(predicates: Ps) -> impl Fn(&T) -> bool
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    move |value: &T| predicates.any(value)
+}
@@ -0,0 +1,218 @@
+use std::fmt::{self, Debug, Formatter};
+
+/// Result of one step of a [`trampoline`]d function.
+///
+/// [`trampoline`]: crate::trampoline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Bounce<A, T> {
+    /// Continue with these arguments
+    Continue(A),
+    /// Stop with this result
+    Done(T),
+}
+
+impl<A, T> Bounce<A, T> {
+    /// Returns `true` if this is [`Bounce::Done`].
+    #[inline]
+    pub fn is_done(&self) -> bool { matches!(self, Bounce::Done(_)) }
+
+    /// Maps the result using `f`, leaving arguments untouched.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> Bounce<A, U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Bounce::Continue(a) => Bounce::Continue(a),
+            Bounce::Done(t) => Bounce::Done(f(t)),
+        }
+    }
+}
+
+/// Run step function `f` in a loop, until it's done.
+///
+/// Takes function `f: A -> Bounce<A, T>` and returns function `A -> T` which
+/// calls `f` until it returns [`Bounce::Done`], passing arguments from
+/// [`Bounce::Continue`] to the next call. Unlike recursion this doesn't grow
+/// the stack.
+///
+/// ## Examples
+/// ```
+/// use fntools::{trampoline, Bounce};
+///
+/// let sum = trampoline(|(n, acc): (u64, u64)| {
+///     if n == 0 {
+///         Bounce::Done(acc)
+///     } else {
+///         Bounce::Continue((n - 1, acc + n))
+///     }
+/// });
+///
+/// // would overflow the stack with usual recursion
+/// assert_eq!(sum((1_000_000, 0)), 500_000_500_000);
+/// ```
+///
+/// Walking a tree with explicit stack:
+/// ```
+/// use fntools::{trampoline, Bounce};
+///
+/// enum Tree {
+///     Leaf(u32),
+///     Node(Vec<Tree>),
+/// }
+///
+/// let tree = Tree::Node(vec![
+///     Tree::Leaf(1),
+///     Tree::Node(vec![Tree::Leaf(7), Tree::Leaf(3)]),
+/// ]);
+/// let max = trampoline(|(mut stack, max): (Vec<&Tree>, u32)| match stack.pop() {
+///     None => Bounce::Done(max),
+///     Some(Tree::Leaf(x)) => Bounce::Continue((stack, max.max(*x))),
+///     Some(Tree::Node(children)) => {
+///         stack.extend(children);
+///         Bounce::Continue((stack, max))
+///     },
+/// });
+///
+/// assert_eq!(max((vec![&tree], 0)), 7);
+/// ```
+///
+/// See also:
+/// - fix-style version of this function: [`trampoline_fix`]
+/// - [`Trampoline`] for mutual recursion
+/// - [`unstable::untuple`] for steps of many arguments
+///
+/// [`trampoline_fix`]: crate::trampoline_fix
+/// [`Trampoline`]: crate::Trampoline
+/// [`unstable::untuple`]: crate::unstable::untuple
+#[inline]
+pub fn trampoline<A, T, F>(f: F) -> impl Fn(A) -> T
+where
+    F: Fn(A) -> Bounce<A, T>,
+{
+    move |mut a: A| loop {
+        match f(a) {
+            Bounce::Continue(next) => a = next,
+            Bounce::Done(res) => return res,
+        }
+    }
+}
+
+/// Run step function which can be called only by unique reference in a loop,
+/// until it's done.
+///
+/// See [trampoline](self::trampoline) for documentation.
+#[inline]
+pub fn trampoline_mut<A, T, F>(mut f: F) -> impl FnMut(A) -> T
+where
+    F: FnMut(A) -> Bounce<A, T>,
+{
+    move |mut a: A| loop {
+        match f(a) {
+            Bounce::Continue(next) => a = next,
+            Bounce::Done(res) => return res,
+        }
+    }
+}
+
+/// [`trampoline`] with [`fix`]-like interface.
+///
+/// `f` receives `recur` which **doesn't** recurse, but returns
+/// [`Bounce::Continue`]. So (tail-)recursive function written for [`fix`] can
+/// be made stack-safe by wrapping base cases into [`Bounce::Done`].
+///
+/// ## Examples
+/// ```
+/// use fntools::{trampoline_fix, Bounce};
+///
+/// let gcd = trampoline_fix(|recur, (a, b): (u64, u64)| {
+///     if b == 0 {
+///         Bounce::Done(a)
+///     } else {
+///         recur((b, a % b))
+///     }
+/// });
+///
+/// assert_eq!(gcd((1071, 462)), 21);
+/// ```
+///
+/// [`trampoline`]: crate::trampoline
+/// [`fix`]: crate::fix
+#[inline]
+pub fn trampoline_fix<A, T, F>(f: F) -> impl Fn(A) -> T
+where
+    F: Fn(fn(A) -> Bounce<A, T>, A) -> Bounce<A, T>,
+{
+    trampoline(move |a: A| f(Bounce::Continue, a))
+}
+
+/// Suspended computation which can be run without growing the stack.
+///
+/// Unlike [`trampoline`] (which repeats one step function) this allows to
+/// jump between different functions, e.g. for mutual recursion.
+///
+/// ## Examples
+/// ```
+/// use fntools::Trampoline;
+///
+/// fn is_even(n: u64) -> Trampoline<'static, bool> {
+///     if n == 0 {
+///         Trampoline::Done(true)
+///     } else {
+///         Trampoline::bounce(move || is_odd(n - 1))
+///     }
+/// }
+///
+/// fn is_odd(n: u64) -> Trampoline<'static, bool> {
+///     if n == 0 {
+///         Trampoline::Done(false)
+///     } else {
+///         Trampoline::bounce(move || is_even(n - 1))
+///     }
+/// }
+///
+/// assert!(is_even(1_000_000).run());
+/// assert!(is_odd(7).run());
+/// ```
+///
+/// [`trampoline`]: crate::trampoline
+pub enum Trampoline<'a, T> {
+    /// Finished computation
+    Done(T),
+    /// Computation which needs to continue by calling the function
+    Bounce(Box<dyn FnOnce() -> Trampoline<'a, T> + 'a>),
+}
+
+impl<'a, T> Trampoline<'a, T> {
+    /// Creates computation which continues by calling `f`.
+    #[inline]
+    pub fn bounce<F>(f: F) -> Self
+    where
+        F: FnOnce() -> Trampoline<'a, T> + 'a,
+    {
+        Trampoline::Bounce(Box::new(f))
+    }
+
+    /// Runs computation to the end.
+    pub fn run(self) -> T {
+        let mut this = self;
+        loop {
+            match this {
+                Trampoline::Done(res) => return res,
+                Trampoline::Bounce(f) => this = f(),
+            }
+        }
+    }
+}
+
+impl<T> Debug for Trampoline<'_, T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Trampoline::Done(res) => f.debug_tuple("Done").field(res).finish(),
+            Trampoline::Bounce(_) => f.debug_tuple("Bounce").field(&"..").finish(),
+        }
+    }
+}
@@ -0,0 +1,125 @@
+use crate::fallible::{Fallible, FromResidual};
+
+/// Chain two fallible functions.
+///
+/// Takes functions `f` and `g` and returns `|a: A| g(f(a)?)`: if `f` returns
+/// `Err`/`None` then `g` isn't called and the error is returned (converted with
+/// `From` just like `?` does).
+///
+/// # Examples
+/// ```
+/// use fntools::try_chain;
+///
+/// let parse = |s: &str| s.parse::<u8>().map_err(|e| e.to_string());
+/// let half = |n: u8| {
+///     if n % 2 == 0 {
+///         Ok(n / 2)
+///     } else {
+///         Err(String::from("odd"))
+///     }
+/// };
+/// let parse_half = try_chain(parse, half);
+///
+/// assert_eq!(parse_half("16"), Ok(8));
+/// assert_eq!(parse_half("17"), Err(String::from("odd")));
+/// assert!(parse_half("256").is_err());
+/// ```
+///
+/// Errors are converted with `From`:
+/// ```
+/// use fntools::try_chain;
+/// use std::num::ParseIntError;
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Error {
+///     Parse,
+///     Zero,
+/// }
+///
+/// impl From<ParseIntError> for Error {
+///     fn from(_: ParseIntError) -> Self { Error::Parse }
+/// }
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let inverse = |n: i32| {
+///     if n == 0 {
+///         Err(Error::Zero)
+///     } else {
+///         Ok(1.0 / n as f64)
+///     }
+/// };
+/// let fun = try_chain(parse, inverse);
+///
+/// assert_eq!(fun("4"), Ok(0.25));
+/// assert_eq!(fun("0"), Err(Error::Zero));
+/// assert_eq!(fun("four"), Err(Error::Parse));
+/// ```
+///
+/// Works with `Option`s too:
+/// ```
+/// use fntools::try_chain;
+///
+/// let first = |s: &str| s.chars().next();
+/// let digit = |c: char| c.to_digit(10);
+/// let first_digit = try_chain(first, digit);
+///
+/// assert_eq!(first_digit("7up"), Some(7));
+/// assert_eq!(first_digit("up"), None);
+/// assert_eq!(first_digit(""), None);
+/// ```
+///
+/// See also:
+/// - [`unstable::try_chain`]
+/// - [`fntools::try_compose`]
+/// - [`fntools::chain`]
+///
+/// [`unstable::try_chain`]: crate::unstable::try_chain
+/// [`fntools::try_compose`]: crate::try_compose
+/// [`fntools::chain`]: crate::chain
+#[inline]
+pub fn try_chain<A, T, U, F, G>(f: F, g: G) -> impl Fn(A) -> U
+where
+    F: Fn(A) -> T,
+    T: Fallible,
+    G: Fn(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match f(a).branch() {
+        Ok(b) => g(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
+
+/// Chain two fallible functions which can be called only once.
+///
+/// See [try_chain](self::try_chain) for documentation.
+#[inline]
+pub fn try_chain_once<A, T, U, F, G>(f: F, g: G) -> impl FnOnce(A) -> U
+where
+    F: FnOnce(A) -> T,
+    T: Fallible,
+    G: FnOnce(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match f(a).branch() {
+        Ok(b) => g(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
+
+/// Chain two fallible functions which can be called only by unique reference.
+///
+/// See [try_chain](self::try_chain) for documentation.
+#[inline]
+pub fn try_chain_mut<A, T, U, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> U
+where
+    F: FnMut(A) -> T,
+    T: Fallible,
+    G: FnMut(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match f(a).branch() {
+        Ok(b) => g(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
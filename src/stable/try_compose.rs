@@ -0,0 +1,74 @@
+use crate::fallible::{Fallible, FromResidual};
+
+/// Compose two fallible functions.
+///
+/// Takes functions `f` and `g` and returns `|a: A| f(g(a)?)`: if `g` returns
+/// `Err`/`None` then `f` isn't called and the error is returned (converted with
+/// `From` just like `?` does).
+///
+/// # Examples
+/// ```
+/// use fntools::try_compose;
+///
+/// let first = |s: &str| s.chars().next();
+/// let digit = |c: char| c.to_digit(10);
+/// let first_digit = try_compose(digit, first);
+///
+/// assert_eq!(first_digit("7up"), Some(7));
+/// assert_eq!(first_digit("up"), None);
+/// ```
+///
+/// See also:
+/// - [`unstable::try_compose`]
+/// - [`fntools::try_chain`]
+///
+/// [`unstable::try_compose`]: crate::unstable::try_compose
+/// [`fntools::try_chain`]: crate::try_chain
+#[inline]
+pub fn try_compose<A, T, U, F, G>(f: F, g: G) -> impl Fn(A) -> U
+where
+    G: Fn(A) -> T,
+    T: Fallible,
+    F: Fn(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match g(a).branch() {
+        Ok(b) => f(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
+
+/// Compose two fallible functions which can be called only once.
+///
+/// See [try_compose](self::try_compose) for documentation.
+#[inline]
+pub fn try_compose_once<A, T, U, F, G>(f: F, g: G) -> impl FnOnce(A) -> U
+where
+    G: FnOnce(A) -> T,
+    T: Fallible,
+    F: FnOnce(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match g(a).branch() {
+        Ok(b) => f(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
+
+/// Compose two fallible functions which can be called only by unique
+/// reference.
+///
+/// See [try_compose](self::try_compose) for documentation.
+#[inline]
+pub fn try_compose_mut<A, T, U, F, G>(mut f: F, mut g: G) -> impl FnMut(A) -> U
+where
+    G: FnMut(A) -> T,
+    T: Fallible,
+    F: FnMut(T::Output) -> U,
+    U: FromResidual<T::Residual>,
+{
+    move |a: A| match g(a).branch() {
+        Ok(b) => f(b),
+        Err(residual) => U::from_residual(residual),
+    }
+}
@@ -0,0 +1,121 @@
+use crate::tuple::accumulate::TupleAccumulate;
+
+/// Tuple of checks (`Fn(&T) -> Result<R, E>`) which can be run all at once.
+///
+/// Implemented for tuples of arity 1 to 12 where all checks have the same
+/// error type.
+///
+/// ## Examples
+/// ```
+/// use fntools::validation::Checks;
+///
+/// let not_empty = |s: &str| if s.is_empty() { Err("empty") } else { Ok(()) };
+/// let len = |s: &str| {
+///     if s.len() > 3 {
+///         Err("too long")
+///     } else {
+///         Ok(s.len())
+///     }
+/// };
+///
+/// assert_eq!((not_empty, len).check_all("abc"), Ok(((), 3)));
+/// assert_eq!((not_empty, len).check_all("abcd"), Err(vec!["too long"]));
+/// ```
+pub trait Checks<T: ?Sized> {
+    /// Tuple of the values produced by successful checks
+    type Res;
+
+    /// Common error type of the checks
+    type Err;
+
+    /// Run **all** checks on `value`, returning either all successes or all
+    /// failures.
+    fn check_all(&self, value: &T) -> Result<Self::Res, Vec<Self::Err>>;
+}
+
+// `for_tuples!` calls this with every prefix of the list, only prefixes made of
+// whole (check, result) pairs are used.
+macro_rules! checks_impl {
+    ($( $f:ident, $r:ident, )+) => {
+        impl<T: ?Sized, E, $( $f, $r, )+> Checks<T> for ($( $f, )+)
+        where
+            $( $f: Fn(&T) -> Result<$r, E>, )+
+        {
+            type Res = ($( $r, )+);
+            type Err = E;
+
+            #[inline]
+            #[allow(non_snake_case)]
+            fn check_all(&self, value: &T) -> Result<Self::Res, Vec<Self::Err>> {
+                let ($( $f, )+) = self;
+                ($( $f(value), )+).accumulate()
+            }
+        }
+    };
+    ($( $other:ident, )+) => {};
+}
+
+for_tuples!(
+    A, RA, B, RB, C, RC, D, RD, E1, RE, F, RF, G, RG, H, RH, I, RI, J, RJ, K, RK, L, RL,
+    # checks_impl
+);
+
+/// Combine tuple of checks into one validating function.
+///
+/// Takes tuple of checks `(c1, c2, ..)` (functions `Fn(&T) -> Result<R, E>`)
+/// and returns function which runs **all** of them, returning either tuple of
+/// successes `(r1, r2, ..)` or `Vec` of **every** failure (in order of the
+/// checks).
+///
+/// # Examples
+/// ```
+/// use fntools::validate;
+///
+/// struct Request {
+///     name: String,
+///     age: u32,
+/// }
+///
+/// let name = |r: &Request| {
+///     if r.name.is_empty() {
+///         Err("name is empty")
+///     } else {
+///         Ok(r.name.len())
+///     }
+/// };
+/// let age = |r: &Request| {
+///     if r.age < 18 {
+///         Err("too young")
+///     } else {
+///         Ok(())
+///     }
+/// };
+/// let check = validate((name, age));
+///
+/// let ok = Request {
+///     name: String::from("Ann"),
+///     age: 42,
+/// };
+/// assert_eq!(check(&ok), Ok((3, ())));
+///
+/// let bad = Request {
+///     name: String::new(),
+///     age: 7,
+/// };
+/// assert_eq!(check(&bad), Err(vec!["name is empty", "too young"]));
+/// ```
+///
+/// See also:
+/// - [`Checks`]
+/// - [`TupleAccumulate`]
+///
+/// [`Checks`]: crate::validation::Checks
+/// [`TupleAccumulate`]: crate::tuple::accumulate::TupleAccumulate
+#[inline]
+pub fn validate<T, C>(checks: C) -> impl Fn(&T) -> Result<C::Res, Vec<C::Err>>
+where
+    T: ?Sized,
+    C: Checks<T>,
+{
+    move |value: &T| checks.check_all(value)
+}
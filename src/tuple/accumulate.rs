@@ -0,0 +1,70 @@
+use crate::{
+    sealed::Sealed,
+    tuple::{append::TupleAppend, take::TupleTake},
+};
+
+/// Transposes tuple of `Result`s into `Result` of tuple, accumulating **all**
+/// errors.
+///
+/// Unlike [`TransposeResult`] this doesn't stop at the first error, all
+/// elements must have the same error type.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::accumulate::TupleAccumulate;
+///
+/// assert_eq!((Ok::<_, ()>(1), Ok("a")).accumulate(), Ok((1, "a")));
+/// assert_eq!(
+///     (Ok::<i32, _>(1), Err::<u8, _>("e1"), Err::<u8, _>("e2")).accumulate(),
+///     Err(vec!["e1", "e2"])
+/// );
+/// ```
+///
+/// [`TransposeResult`]: crate::tuple::transpose::TransposeResult
+pub trait TupleAccumulate: Sized + Sealed {
+    /// Tuple of the values of the `Result`s
+    type Res;
+
+    /// Common error type of the `Result`s
+    type Err;
+
+    /// Transposes tuple of `Result`s, collecting all errors.
+    fn accumulate(self) -> Result<Self::Res, Vec<Self::Err>>;
+}
+
+impl<X, E> TupleAccumulate for (Result<X, E>,) {
+    type Err = E;
+    type Res = (X,);
+
+    #[inline]
+    fn accumulate(self) -> Result<Self::Res, Vec<Self::Err>> {
+        match self.0 {
+            Ok(x) => Ok((x,)),
+            Err(e) => Err(vec![e]),
+        }
+    }
+}
+
+impl<T, X, E> TupleAccumulate for T
+where
+    T: TupleTake<Take = Result<X, E>>,
+    T::Rem: TupleAccumulate<Err = E>,
+    <T::Rem as TupleAccumulate>::Res: TupleAppend<X>,
+{
+    type Err = E;
+    type Res = <<T::Rem as TupleAccumulate>::Res as TupleAppend<X>>::Res;
+
+    #[inline]
+    fn accumulate(self) -> Result<Self::Res, Vec<Self::Err>> {
+        let (head, rest) = self.take();
+        match (head, rest.accumulate()) {
+            (Ok(x), Ok(rest)) => Ok(rest.append(x)),
+            (Ok(_), Err(errors)) => Err(errors),
+            (Err(e), Ok(_)) => Err(vec![e]),
+            (Err(e), Err(mut errors)) => {
+                errors.insert(0, e);
+                Err(errors)
+            },
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    sealed::Sealed,
+    tuple::{append::TupleAppend, take::TupleTake},
+};
+
+/// Cartesian product of a tuple of iterables.
+///
+/// Produces every combination of the elements, the first element of the tuple
+/// changes the slowest. Elements are cloned since every one of them takes part
+/// in many combinations.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::cartesian::TupleCartesian;
+///
+/// assert_eq!(
+///     (vec![1, 2], "ab".chars()).cartesian(),
+///     [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]
+/// );
+/// assert_eq!((vec![1, 2], Vec::<char>::new()).cartesian(), []);
+/// assert_eq!(().cartesian(), [()]);
+/// ```
+pub trait TupleCartesian: Sized + Sealed {
+    /// Tuple of the items of the iterables
+    type Res;
+
+    /// Collects all combinations of the elements.
+    fn cartesian(self) -> Vec<Self::Res>;
+}
+
+impl TupleCartesian for () {
+    type Res = ();
+
+    #[inline]
+    fn cartesian(self) -> Vec<Self::Res> { vec![()] }
+}
+
+impl<T> TupleCartesian for T
+where
+    T: TupleTake,
+    T::Take: IntoIterator,
+    <T::Take as IntoIterator>::Item: Clone,
+    T::Rem: TupleCartesian,
+    <T::Rem as TupleCartesian>::Res: TupleAppend<<T::Take as IntoIterator>::Item> + Clone,
+{
+    type Res =
+        <<T::Rem as TupleCartesian>::Res as TupleAppend<<T::Take as IntoIterator>::Item>>::Res;
+
+    #[inline]
+    fn cartesian(self) -> Vec<Self::Res> {
+        let (head, rest) = self.take();
+        let rest = rest.cartesian();
+        head.into_iter()
+            .flat_map(|x| rest.iter().map(move |r| r.clone().append(x.clone())))
+            .collect()
+    }
+}
@@ -0,0 +1,61 @@
+use crate::sealed::Sealed;
+
+/// Get `N`-th (counting from 0) element of the tuple.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::nth::TupleNth;
+///
+/// assert_eq!(TupleNth::<0>::nth((1, "a", true)), 1);
+/// assert_eq!(TupleNth::<2>::nth((1, "a", true)), true);
+/// ```
+///
+/// ```compile_fail
+/// use fntools::tuple::nth::TupleNth;
+///
+/// // There is no element with index 2 in the tuple of 2 elements
+/// TupleNth::<2>::nth((1, 2));
+/// ```
+pub trait TupleNth<const N: usize>: Sized + Sealed {
+    /// Type of the `N`-th element
+    type Item;
+
+    /// Returns `N`-th element of the tuple, dropping the others.
+    fn nth(self) -> Self::Item;
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        tuple_impl!(@pos 0, [] [$( $types )*]);
+    };
+    (@pos $n:expr, [$( $before:ident )*] [$cur:ident $( $after:ident )*]) => {
+        impl<$( $before, )* $cur, $( $after, )*> TupleNth<{ $n }> for ($( $before, )* $cur, $( $after, )*) {
+            type Item = $cur;
+
+            #[inline]
+            #[allow(non_snake_case, unused_variables)]
+            fn nth(self) -> Self::Item {
+                let ($( $before, )* $cur, $( $after, )*) = self;
+                $cur
+            }
+        }
+
+        tuple_impl!(@pos $n + 1, [$( $before )* $cur] [$( $after )*]);
+    };
+    (@pos $n:expr, [$( $before:ident )*] []) => {};
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::nth::TupleNth;
+
+    #[test]
+    fn arity_12() {
+        let t = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+        assert_eq!(TupleNth::<0>::nth(t), 0);
+        assert_eq!(TupleNth::<5>::nth(t), 5);
+        assert_eq!(TupleNth::<11>::nth(t), 11);
+    }
+}
@@ -0,0 +1,80 @@
+use crate::sealed::Sealed;
+
+/// Get first `N` elements of the tuple.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::prefix::TuplePrefix;
+///
+/// assert_eq!(TuplePrefix::<2>::prefix((1, "a", true)), (1, "a"));
+/// assert_eq!(TuplePrefix::<0>::prefix((1, "a", true)), ());
+/// assert_eq!(TuplePrefix::<3>::prefix((1, "a", true)), (1, "a", true));
+/// ```
+///
+/// ```compile_fail
+/// use fntools::tuple::prefix::TuplePrefix;
+///
+/// // There are only 2 elements in the tuple
+/// TuplePrefix::<3>::prefix((1, 2));
+/// ```
+pub trait TuplePrefix<const N: usize>: Sized + Sealed {
+    /// Tuple of the first `N` elements
+    type Prefix;
+
+    /// Returns first `N` elements of the tuple, dropping the others.
+    fn prefix(self) -> Self::Prefix;
+}
+
+impl TuplePrefix<0> for () {
+    type Prefix = ();
+
+    #[inline]
+    fn prefix(self) -> Self::Prefix {}
+}
+
+macro_rules! tuple_impl {
+    ($( $types:ident, )*) => {
+        tuple_impl!(@pos 0, [] [$( $types )*]);
+    };
+    (@pos $n:expr, [$( $before:ident )*] [$cur:ident $( $after:ident )*]) => {
+        impl<$( $before, )* $cur, $( $after, )*> TuplePrefix<{ $n }> for ($( $before, )* $cur, $( $after, )*) {
+            type Prefix = ($( $before, )*);
+
+            #[inline]
+            #[allow(non_snake_case, unused_variables, clippy::unused_unit)]
+            fn prefix(self) -> Self::Prefix {
+                let ($( $before, )* $cur, $( $after, )*) = self;
+                ($( $before, )*)
+            }
+        }
+
+        tuple_impl!(@pos $n + 1, [$( $before )* $cur] [$( $after )*]);
+    };
+    (@pos $n:expr, [$( $before:ident )*] []) => {
+        impl<$( $before, )*> TuplePrefix<{ $n }> for ($( $before, )*) {
+            type Prefix = Self;
+
+            #[inline]
+            fn prefix(self) -> Self::Prefix { self }
+        }
+    };
+}
+
+for_tuples!(A, B, C, D, E, F, G, H, I, J, K, L, # tuple_impl);
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::prefix::TuplePrefix;
+
+    #[test]
+    fn arity_12() {
+        let t = (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11);
+        assert_eq!(TuplePrefix::<0>::prefix(t), ());
+        assert_eq!(TuplePrefix::<1>::prefix(t), (0,));
+        assert_eq!(
+            TuplePrefix::<11>::prefix(t),
+            (0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10)
+        );
+        assert_eq!(TuplePrefix::<12>::prefix(t), t);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::{
+    sealed::Sealed,
+    tuple::{append::TupleAppend, take::TupleTake},
+};
+
+/// Transposes tuple of `Option`s into `Option` of tuple.
+///
+/// Returns `None` if any of the elements is `None`.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::transpose::TransposeOption;
+///
+/// assert_eq!((Some(1), Some("a")).transpose_option(), Some((1, "a")));
+/// assert_eq!((Some(1), None::<&str>).transpose_option(), None);
+/// assert_eq!(().transpose_option(), Some(()));
+/// ```
+pub trait TransposeOption: Sized + Sealed {
+    /// Tuple of the values of the `Option`s
+    type Res;
+
+    /// Transposes tuple of `Option`s.
+    fn transpose_option(self) -> Option<Self::Res>;
+}
+
+impl TransposeOption for () {
+    type Res = ();
+
+    #[inline]
+    fn transpose_option(self) -> Option<Self::Res> { Some(()) }
+}
+
+impl<T, X> TransposeOption for T
+where
+    T: TupleTake<Take = Option<X>>,
+    T::Rem: TransposeOption,
+    <T::Rem as TransposeOption>::Res: TupleAppend<X>,
+{
+    type Res = <<T::Rem as TransposeOption>::Res as TupleAppend<X>>::Res;
+
+    #[inline]
+    fn transpose_option(self) -> Option<Self::Res> {
+        let (head, rest) = self.take();
+        let head = head?;
+        let rest = rest.transpose_option()?;
+        Some(rest.append(head))
+    }
+}
+
+/// Transposes tuple of `Result`s into `Result` of tuple.
+///
+/// Returns the first error if any of the elements is `Err`. All elements must
+/// have the same error type.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::transpose::TransposeResult;
+///
+/// assert_eq!((Ok::<_, ()>(1), Ok("a")).transpose_result(), Ok((1, "a")));
+/// assert_eq!(
+///     (Ok::<i32, _>(1), Err::<u8, _>("e1"), Err::<u8, _>("e2")).transpose_result(),
+///     Err("e1")
+/// );
+/// ```
+pub trait TransposeResult: Sized + Sealed {
+    /// Tuple of the values of the `Result`s
+    type Res;
+
+    /// Common error type of the `Result`s
+    type Err;
+
+    /// Transposes tuple of `Result`s.
+    fn transpose_result(self) -> Result<Self::Res, Self::Err>;
+}
+
+impl<X, E> TransposeResult for (Result<X, E>,) {
+    type Err = E;
+    type Res = (X,);
+
+    #[inline]
+    fn transpose_result(self) -> Result<Self::Res, Self::Err> { Ok((self.0?,)) }
+}
+
+impl<T, X, E> TransposeResult for T
+where
+    T: TupleTake<Take = Result<X, E>>,
+    T::Rem: TransposeResult<Err = E>,
+    <T::Rem as TransposeResult>::Res: TupleAppend<X>,
+{
+    type Err = E;
+    type Res = <<T::Rem as TransposeResult>::Res as TupleAppend<X>>::Res;
+
+    #[inline]
+    fn transpose_result(self) -> Result<Self::Res, Self::Err> {
+        let (head, rest) = self.take();
+        let head = head?;
+        let rest = rest.transpose_result()?;
+        Ok(rest.append(head))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tuple::transpose::{TransposeOption, TransposeResult};
+
+    #[test]
+    fn option_arity_12() {
+        let s = Some(0);
+        assert_eq!(
+            (s, s, s, s, s, s, s, s, s, s, s, s).transpose_option(),
+            Some((0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            (s, s, s, s, s, s, s, s, s, s, s, None::<i32>).transpose_option(),
+            None
+        );
+    }
+
+    #[test]
+    fn result_arity_12() {
+        let o = Ok::<_, &str>(0);
+        assert_eq!(
+            (o, o, o, o, o, o, o, o, o, o, o, o).transpose_result(),
+            Ok((0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0))
+        );
+        assert_eq!(
+            (
+                o,
+                Err::<i32, _>("first"),
+                o,
+                o,
+                o,
+                o,
+                o,
+                o,
+                o,
+                o,
+                o,
+                Err::<i32, _>("last")
+            )
+                .transpose_result(),
+            Err("first")
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use crate::sealed::Sealed;
+
+/// Apply tuple of functions to tuple of arguments element-wise, consuming
+/// the functions.
+///
+/// Implemented for tuples of arity 0 to 12.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::zip_apply::TupleZipApplyOnce;
+///
+/// let s = String::from("a");
+/// let fns = (move |x: &str| s + x, |n: i32| n * 2);
+///
+/// assert_eq!(fns.zip_apply_once(("b", 21)), (String::from("ab"), 42));
+/// ```
+pub trait TupleZipApplyOnce<Args>: Sized + Sealed {
+    /// Tuple of the results of the functions
+    type Res;
+
+    /// Apply `i`-th function to `i`-th argument.
+    fn zip_apply_once(self, args: Args) -> Self::Res;
+}
+
+/// Apply tuple of functions to tuple of arguments element-wise, by unique
+/// reference.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::zip_apply::TupleZipApplyMut;
+///
+/// let mut calls = 0;
+/// let mut fns = (
+///     |x: i32| {
+///         calls += 1;
+///         x
+///     },
+///     |x: i32| -x,
+/// );
+///
+/// assert_eq!(fns.zip_apply_mut((1, 2)), (1, -2));
+/// assert_eq!(fns.zip_apply_mut((3, 4)), (3, -4));
+/// drop(fns);
+/// assert_eq!(calls, 2);
+/// ```
+pub trait TupleZipApplyMut<Args>: TupleZipApplyOnce<Args> {
+    /// Apply `i`-th function to `i`-th argument.
+    fn zip_apply_mut(&mut self, args: Args) -> Self::Res;
+}
+
+/// Apply tuple of functions to tuple of arguments element-wise.
+///
+/// ## Examples
+/// ```
+/// use fntools::tuple::zip_apply::TupleZipApply;
+///
+/// let fns = (str::len, |x: i32| x + 1, char::is_alphabetic);
+///
+/// assert_eq!(fns.zip_apply(("abc", 1, '!')), (3, 2, false));
+/// assert_eq!(().zip_apply(()), ());
+/// ```
+pub trait TupleZipApply<Args>: TupleZipApplyMut<Args> {
+    /// Apply `i`-th function to `i`-th argument.
+    fn zip_apply(&self, args: Args) -> Self::Res;
+}
+
+// `for_tuples!` calls this with every prefix of the list, only prefixes made of
+// whole (function, argument, result) triples are used.
+macro_rules! tuple_impl {
+    ($( $f:ident, $a:ident, $r:ident, )*) => {
+        impl<$( $f, $a, $r, )*> TupleZipApplyOnce<($( $a, )*)> for ($( $f, )*)
+        where
+            $( $f: FnOnce($a) -> $r, )*
+        {
+            type Res = ($( $r, )*);
+
+            #[inline]
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn zip_apply_once(self, ($( $a, )*): ($( $a, )*)) -> Self::Res {
+                let ($( $f, )*) = self;
+                ($( $f($a), )*)
+            }
+        }
+
+        impl<$( $f, $a, $r, )*> TupleZipApplyMut<($( $a, )*)> for ($( $f, )*)
+        where
+            $( $f: FnMut($a) -> $r, )*
+        {
+            #[inline]
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn zip_apply_mut(&mut self, ($( $a, )*): ($( $a, )*)) -> Self::Res {
+                let ($( $f, )*) = self;
+                ($( $f($a), )*)
+            }
+        }
+
+        impl<$( $f, $a, $r, )*> TupleZipApply<($( $a, )*)> for ($( $f, )*)
+        where
+            $( $f: Fn($a) -> $r, )*
+        {
+            #[inline]
+            #[allow(non_snake_case, clippy::unused_unit)]
+            fn zip_apply(&self, ($( $a, )*): ($( $a, )*)) -> Self::Res {
+                let ($( $f, )*) = self;
+                ($( $f($a), )*)
+            }
+        }
+    };
+    ($( $other:ident, )*) => {};
+}
+
+tuple_impl!();
+for_tuples!(
+    FA, AA, RA,
+    FB, AB, RB,
+    FC, AC, RC,
+    FD, AD, RD,
+    FE, AE, RE,
+    FF, AF, RF,
+    FG, AG, RG,
+    FH, AH, RH,
+    FI, AI, RI,
+    FJ, AJ, RJ,
+    FK, AK, RK,
+    FL, AL, RL,
+    # tuple_impl
+);
@@ -0,0 +1,302 @@
+use crate::tuple::prefix::TuplePrefix;
+
+/// Ignore all arguments of the function.
+///
+/// Takes function `f: () -> R` and returns function which accepts any number
+/// of arguments (of any types), drops them and calls `f()`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::ignore_args;
+///
+/// let ping = ignore_args(|| "pong");
+///
+/// assert_eq!(ping(), "pong");
+/// assert_eq!(ping(1, "two", 3.0), "pong");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::ignore_args`]
+/// - extension on all functions: [`FnExt::ignore_args`]
+///
+/// [`fntools::ignore_args`]: crate::ignore_args
+/// [`FnExt::ignore_args`]: crate::unstable::FnExt::ignore_args
+#[inline]
+pub fn ignore_args<F>(f: F) -> IgnoreArgs<F>
+where
+    F: FnOnce<()>,
+{
+    IgnoreArgs::new(f)
+}
+
+/// Represents function `F` which ignores its arguments.
+///
+/// For documentation see [`ignore_args`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct IgnoreArgs<F>(F);
+
+impl<F> IgnoreArgs<F> {
+    /// Creates version of the function `f` which ignores its arguments.
+    ///
+    /// It's preferred to use [`ignore_args`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        F: FnOnce<()>,
+    {
+        IgnoreArgs(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let IgnoreArgs(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let IgnoreArgs(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for IgnoreArgs<F>
+where
+    F: FnOnce<()>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, _args: A) -> Self::Output {
+        let IgnoreArgs(f) = self;
+        f()
+    }
+}
+
+impl<A, F> FnMut<A> for IgnoreArgs<F>
+where
+    F: FnMut<()>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, _args: A) -> Self::Output {
+        let IgnoreArgs(f) = self;
+        f()
+    }
+}
+
+impl<A, F> Fn<A> for IgnoreArgs<F>
+where
+    F: Fn<()>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, _args: A) -> Self::Output {
+        let IgnoreArgs(f) = self;
+        f()
+    }
+}
+
+/// Duplicate argument of the function.
+///
+/// Takes function `f: A, A -> B` and returns `|a: A| f(a.clone(), a)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::dup;
+///
+/// let twice = dup(|a: String, b: String| a + &b);
+///
+/// assert_eq!(twice(String::from("ab")), "abab");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::dup`]
+/// - extension on all functions: [`FnExt::dup`]
+///
+/// [`fntools::dup`]: crate::dup
+/// [`FnExt::dup`]: crate::unstable::FnExt::dup
+#[inline]
+pub fn dup<A, F>(f: F) -> Dup<F>
+where
+    F: FnOnce<(A, A)>,
+    A: Clone,
+{
+    Dup::new(f)
+}
+
+/// Represents function `F` with duplicated argument.
+///
+/// For documentation see [`dup`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Dup<F>(F);
+
+impl<F> Dup<F> {
+    /// Creates version of the function `f` with duplicated argument.
+    ///
+    /// It's preferred to use [`dup`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<(A, A)>,
+        A: Clone,
+    {
+        Dup(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let Dup(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let Dup(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<(A,)> for Dup<F>
+where
+    F: FnOnce<(A, A)>,
+    A: Clone,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+        let Dup(f) = self;
+        f(a.clone(), a)
+    }
+}
+
+impl<A, F> FnMut<(A,)> for Dup<F>
+where
+    F: FnMut<(A, A)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        let Dup(f) = self;
+        f(a.clone(), a)
+    }
+}
+
+impl<A, F> Fn<(A,)> for Dup<F>
+where
+    F: Fn<(A, A)>,
+    A: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+        let Dup(f) = self;
+        f(a.clone(), a)
+    }
+}
+
+/// Call function with only first `N` arguments (like [Ramda's `nAry`]).
+///
+/// Returned function accepts `N` or more arguments, extra arguments are
+/// dropped.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::take_args;
+///
+/// // callback with signature `Fn(&str, usize, bool)`
+/// fn for_each_item(callback: impl Fn(&str, usize, bool)) { callback("a", 0, true); }
+///
+/// for_each_item(take_args::<1, _>(|name: &str| assert_eq!(name, "a")));
+/// ```
+///
+/// ## Limitations
+///
+/// - Since explicit generic arguments can't be partially specified, the
+///   function type has to be elided explicitly: `take_args::<N, _>(f)`. The
+///   [`FnExt::take_args`] method doesn't have this problem:
+///   `f.take_args::<N>()`.
+/// - There is no stable version of this function: stable closures can't accept
+///   a variable number of arguments.
+///
+/// See also:
+/// - extension on all functions: [`FnExt::take_args`]
+/// - [`TuplePrefix`]
+///
+/// [Ramda's `nAry`]: https://ramdajs.com/docs/#nAry
+/// [`FnExt::take_args`]: crate::unstable::FnExt::take_args
+/// [`TuplePrefix`]: crate::tuple::prefix::TuplePrefix
+#[inline]
+pub fn take_args<const N: usize, F>(f: F) -> TakeArgs<F, N> { TakeArgs::new(f) }
+
+/// Represents function `F` called with only first `N` arguments.
+///
+/// For documentation see [`take_args`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct TakeArgs<F, const N: usize>(F);
+
+impl<F, const N: usize> TakeArgs<F, N> {
+    /// Creates version of the function `f` which is called with only first `N`
+    /// arguments.
+    ///
+    /// It's preferred to use [`take_args`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self { TakeArgs(f) }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let TakeArgs(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let TakeArgs(f) = self;
+        f
+    }
+}
+
+impl<A, F, const N: usize> FnOnce<A> for TakeArgs<F, N>
+where
+    A: TuplePrefix<N>,
+    F: FnOnce<A::Prefix>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let TakeArgs(f) = self;
+        f.call_once(args.prefix())
+    }
+}
+
+impl<A, F, const N: usize> FnMut<A> for TakeArgs<F, N>
+where
+    A: TuplePrefix<N>,
+    F: FnMut<A::Prefix>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let TakeArgs(f) = self;
+        f.call_mut(args.prefix())
+    }
+}
+
+impl<A, F, const N: usize> Fn<A> for TakeArgs<F, N>
+where
+    A: TuplePrefix<N>,
+    F: Fn<A::Prefix>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let TakeArgs(f) = self;
+        f.call(args.prefix())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{tuple::nth::TupleNth, Const, Id, Proj};
+
+impl<T> FnOnce<(T,)> for Id {
+    type Output = T;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (x,): (T,)) -> Self::Output { x }
+}
+
+impl<T> FnMut<(T,)> for Id {
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output { x }
+}
+
+impl<T> Fn<(T,)> for Id {
+    #[inline]
+    extern "rust-call" fn call(&self, (x,): (T,)) -> Self::Output { x }
+}
+
+impl<A, T> FnOnce<A> for Const<T> {
+    type Output = T;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, _args: A) -> Self::Output {
+        let Const(value) = self;
+        value
+    }
+}
+
+impl<A, T> FnMut<A> for Const<T>
+where
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, _args: A) -> Self::Output {
+        let Const(value) = self;
+        value.clone()
+    }
+}
+
+impl<A, T> Fn<A> for Const<T>
+where
+    T: Clone,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, _args: A) -> Self::Output {
+        let Const(value) = self;
+        value.clone()
+    }
+}
+
+impl<A, const N: usize> FnOnce<A> for Proj<N>
+where
+    A: TupleNth<N>,
+{
+    type Output = A::Item;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { args.nth() }
+}
+
+impl<A, const N: usize> FnMut<A> for Proj<N>
+where
+    A: TupleNth<N>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { args.nth() }
+}
+
+impl<A, const N: usize> Fn<A> for Proj<N>
+where
+    A: TupleNth<N>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output { args.nth() }
+}
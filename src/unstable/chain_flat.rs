@@ -0,0 +1,125 @@
+use std::iter::FlatMap;
+
+/// Chain two one-to-many functions.
+///
+/// Takes functions `f` (returning `impl IntoIterator<Item = B>`) and
+/// `g: B -> impl IntoIterator<Item = C>` and returns something _like_
+/// `|a: A| f(a).into_iter().flat_map(g)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_flat;
+///
+/// let range = |from: u32, to: u32| from..to;
+/// let twice = |n: u32| vec![n, n];
+/// let fun = chain_flat(range, twice);
+///
+/// assert_eq!(fun(1, 4).collect::<Vec<_>>(), [1, 1, 2, 2, 3, 3]);
+/// ```
+///
+/// ## Note
+///
+/// When called by (unique) reference `g` is cloned, since the returned
+/// iterator has to own it. That's why in this case `g` has to be [`Fn`]: state
+/// mutated by a clone of `g` wouldn't be preserved between calls anyway.
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_flat`]
+/// - extension on all functions: [`FnExt::flat_chain`]
+///
+/// [`FnExt::flat_chain`]: crate::unstable::FnExt::flat_chain
+/// [`fntools::chain_flat`]: crate::chain_flat
+#[inline]
+pub fn chain_flat<A, F, G>(f: F, g: G) -> ChainFlat<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: IntoIterator,
+    G: FnMut<(<F::Output as IntoIterator>::Item,)>,
+    G::Output: IntoIterator,
+{
+    ChainFlat::new(f, g)
+}
+
+/// Represents one-to-many composition of 2 functions.
+///
+/// For documentation see [`chain_flat`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct ChainFlat<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> ChainFlat<F, G> {
+    /// Creates one-to-many chain of functions `f` and `g`.
+    ///
+    /// It's preferred to use [`chain_flat`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        F::Output: IntoIterator,
+        G: FnMut<(<F::Output as IntoIterator>::Item,)>,
+        G::Output: IntoIterator,
+    {
+        ChainFlat { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ChainFlat { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ChainFlat { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for ChainFlat<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: IntoIterator,
+    G: FnMut<(<F::Output as IntoIterator>::Item,)>,
+    G::Output: IntoIterator,
+{
+    type Output = FlatMap<<F::Output as IntoIterator>::IntoIter, G::Output, G>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let ChainFlat { f, g } = self;
+        f.call_once(args).into_iter().flat_map(g)
+    }
+}
+
+impl<A, F, G> FnMut<A> for ChainFlat<F, G>
+where
+    F: FnMut<A>,
+    F::Output: IntoIterator,
+    G: Fn<(<F::Output as IntoIterator>::Item,)> + Clone,
+    G::Output: IntoIterator,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let ChainFlat { f, g } = self;
+        f.call_mut(args).into_iter().flat_map(g.clone())
+    }
+}
+
+impl<A, F, G> Fn<A> for ChainFlat<F, G>
+where
+    F: Fn<A>,
+    F::Output: IntoIterator,
+    G: Fn<(<F::Output as IntoIterator>::Item,)> + Clone,
+    G::Output: IntoIterator,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let ChainFlat { f, g } = self;
+        f.call(args).into_iter().flat_map(g.clone())
+    }
+}
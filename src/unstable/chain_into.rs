@@ -0,0 +1,338 @@
+use std::{
+    convert::TryFrom,
+    fmt::{Debug, Error, Formatter},
+    marker::PhantomData,
+};
+
+/// Chain two functions, converting output of the first one with `From`.
+///
+/// Takes functions `f` and `g` and returns `|args..| g(X::from(f(args..)))`
+/// where `X` is the argument type of `g`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_into;
+///
+/// let area = chain_into(|w: u32, h: u32| w * h, |a: u64| a * 1_000_000);
+///
+/// assert_eq!(area(5_000, 5_000), 25_000_000_000_000);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_into`]
+/// - extension on all functions: [`FnExt::chain_into`]
+///
+/// [`fntools::chain_into`]: crate::chain_into
+/// [`FnExt::chain_into`]: crate::unstable::FnExt::chain_into
+#[inline]
+pub fn chain_into<A, X, F, G>(f: F, g: G) -> ChainInto<F, G, X>
+where
+    F: FnOnce<A>,
+    X: From<F::Output>,
+    G: FnOnce<(X,)>,
+{
+    ChainInto::new(f, g)
+}
+
+/// Compose two functions, converting output of the second one with `From`.
+///
+/// Takes functions `f` and `g` and returns `|args..| f(X::from(g(args..)))`
+/// where `X` is the argument type of `f`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::compose_into;
+///
+/// let fun = compose_into(|c: char| c.is_alphabetic(), |b: u8| b + 1);
+///
+/// assert!(fun(b'a'));
+/// assert!(!fun(b'0'));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::compose_into`]
+/// - extension on all functions: [`FnExt::compose_into`]
+///
+/// [`fntools::compose_into`]: crate::compose_into
+/// [`FnExt::compose_into`]: crate::unstable::FnExt::compose_into
+#[inline]
+pub fn compose_into<A, X, F, G>(f: F, g: G) -> ChainInto<G, F, X>
+where
+    G: FnOnce<A>,
+    X: From<G::Output>,
+    F: FnOnce<(X,)>,
+{
+    ChainInto::new(g, f)
+}
+
+/// Represents composition of 2 functions `G ∘ X::from ∘ F`.
+///
+/// For documentation see [`chain_into`] and [`compose_into`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct ChainInto<F, G, X> {
+    f: F,
+    g: G,
+    marker: PhantomData<fn(X)>,
+}
+
+impl<F, G, X> ChainInto<F, G, X> {
+    /// Creates chain of functions `f` and `g` with `From` conversion between
+    /// them.
+    ///
+    /// It's preferred to use [`chain_into`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        X: From<F::Output>,
+        G: FnOnce<(X,)>,
+    {
+        ChainInto {
+            f,
+            g,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ChainInto { f, g, marker: _ } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ChainInto { f, g, marker: _ } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G, X> FnOnce<A> for ChainInto<F, G, X>
+where
+    F: FnOnce<A>,
+    X: From<F::Output>,
+    G: FnOnce<(X,)>,
+{
+    type Output = G::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let ChainInto { f, g, .. } = self;
+        g(X::from(f.call_once(args)))
+    }
+}
+
+impl<A, F, G, X> FnMut<A> for ChainInto<F, G, X>
+where
+    F: FnMut<A>,
+    X: From<F::Output>,
+    G: FnMut<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let ChainInto { f, g, .. } = self;
+        g(X::from(f.call_mut(args)))
+    }
+}
+
+impl<A, F, G, X> Fn<A> for ChainInto<F, G, X>
+where
+    F: Fn<A>,
+    X: From<F::Output>,
+    G: Fn<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let ChainInto { f, g, .. } = self;
+        g(X::from(f.call(args)))
+    }
+}
+
+impl<F, G, X> Debug for ChainInto<F, G, X>
+where
+    F: Debug,
+    G: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("ChainInto")
+            .field("f", &self.f)
+            .field("g", &self.g)
+            .finish()
+    }
+}
+
+impl<F, G, X> Clone for ChainInto<F, G, X>
+where
+    F: Clone,
+    G: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        ChainInto {
+            f: self.f.clone(),
+            g: self.g.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, G, X> Copy for ChainInto<F, G, X>
+where
+    F: Copy,
+    G: Copy,
+{
+}
+
+/// Chain two functions, converting output of the first one with `TryFrom`.
+///
+/// Takes functions `f` and `g` and returns
+/// `|args..| X::try_from(f(args..)).map(g)` where `X` is the argument type of
+/// `g`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::try_chain_into;
+///
+/// let fun = try_chain_into(|a: u32, b: u32| a + b, |c: char| c.to_ascii_uppercase());
+///
+/// assert_eq!(fun(0x60, 1), Ok('A'));
+/// assert!(fun(0xD800, 0).is_err());
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::try_chain_into`]
+/// - extension on all functions: [`FnExt::try_chain_into`]
+///
+/// [`fntools::try_chain_into`]: crate::try_chain_into
+/// [`FnExt::try_chain_into`]: crate::unstable::FnExt::try_chain_into
+#[inline]
+pub fn try_chain_into<A, X, F, G>(f: F, g: G) -> TryChainInto<F, G, X>
+where
+    F: FnOnce<A>,
+    X: TryFrom<F::Output>,
+    G: FnOnce<(X,)>,
+{
+    TryChainInto::new(f, g)
+}
+
+/// Represents composition of 2 functions `G ∘ X::try_from ∘ F`.
+///
+/// For documentation see [`try_chain_into`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct TryChainInto<F, G, X> {
+    f: F,
+    g: G,
+    marker: PhantomData<fn(X)>,
+}
+
+impl<F, G, X> TryChainInto<F, G, X> {
+    /// Creates chain of functions `f` and `g` with `TryFrom` conversion
+    /// between them.
+    ///
+    /// It's preferred to use [`try_chain_into`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        X: TryFrom<F::Output>,
+        G: FnOnce<(X,)>,
+    {
+        TryChainInto {
+            f,
+            g,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let TryChainInto { f, g, marker: _ } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let TryChainInto { f, g, marker: _ } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G, X> FnOnce<A> for TryChainInto<F, G, X>
+where
+    F: FnOnce<A>,
+    X: TryFrom<F::Output>,
+    G: FnOnce<(X,)>,
+{
+    type Output = Result<G::Output, X::Error>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let TryChainInto { f, g, .. } = self;
+        X::try_from(f.call_once(args)).map(g)
+    }
+}
+
+impl<A, F, G, X> FnMut<A> for TryChainInto<F, G, X>
+where
+    F: FnMut<A>,
+    X: TryFrom<F::Output>,
+    G: FnMut<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let TryChainInto { f, g, .. } = self;
+        X::try_from(f.call_mut(args)).map(g)
+    }
+}
+
+impl<A, F, G, X> Fn<A> for TryChainInto<F, G, X>
+where
+    F: Fn<A>,
+    X: TryFrom<F::Output>,
+    G: Fn<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let TryChainInto { f, g, .. } = self;
+        X::try_from(f.call(args)).map(g)
+    }
+}
+
+impl<F, G, X> Debug for TryChainInto<F, G, X>
+where
+    F: Debug,
+    G: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("TryChainInto")
+            .field("f", &self.f)
+            .field("g", &self.g)
+            .finish()
+    }
+}
+
+impl<F, G, X> Clone for TryChainInto<F, G, X>
+where
+    F: Clone,
+    G: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        TryChainInto {
+            f: self.f.clone(),
+            g: self.g.clone(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<F, G, X> Copy for TryChainInto<F, G, X>
+where
+    F: Copy,
+    G: Copy,
+{
+}
@@ -0,0 +1,212 @@
+/// Chain two functions where the first one returns borrow of its argument.
+///
+/// Takes functions `f: for<'a> &'a A -> &'a B` and `g: &B -> C` and returns
+/// `for<'a> |a: &'a A| g(f(a))`. Output of `g` may also borrow from its
+/// argument (see [`chain_proj`]).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_ref;
+///
+/// let trimmed_len = chain_ref(str::trim, str::len);
+///
+/// assert_eq!(trimmed_len("  abc "), 3);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_ref`]
+/// - extension on all functions: [`FnExt::chain_ref`]
+///
+/// [`fntools::chain_ref`]: crate::chain_ref
+/// [`FnExt::chain_ref`]: crate::unstable::FnExt::chain_ref
+#[inline]
+pub fn chain_ref<A, B, F, G>(f: F, g: G) -> ChainRef<F, G>
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+    G: for<'b> FnOnce<(&'b B,)>,
+{
+    ChainRef::new(f, g)
+}
+
+/// Chain two projections (functions returning borrows of their arguments).
+///
+/// Takes functions `f: for<'a> &'a A -> &'a B` and
+/// `g: for<'b> &'b B -> &'b C` and returns `for<'a> |a: &'a A| -> &'a C`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_proj;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let name = chain_proj(|c: &Config| &c.name, |s: &String| s.as_str());
+/// let config = Config {
+///     name: String::from("fntools"),
+/// };
+///
+/// assert_eq!(name(&config), "fntools");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_proj`]
+/// - extension on all functions: [`FnExt::chain_proj`]
+///
+/// [`fntools::chain_proj`]: crate::chain_proj
+/// [`FnExt::chain_proj`]: crate::unstable::FnExt::chain_proj
+#[inline]
+pub fn chain_proj<A, B, C, F, G>(f: F, g: G) -> ChainRef<F, G>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: ?Sized,
+    F: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+    G: for<'b> FnOnce<(&'b B,), Output = &'b C>,
+{
+    ChainRef::new(f, g)
+}
+
+/// Compose two functions where the second one returns borrow of its
+/// argument.
+///
+/// Takes functions `f: &B -> C` and `g: for<'a> &'a A -> &'a B` and returns
+/// `for<'a> |a: &'a A| f(g(a))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::compose_ref;
+///
+/// let first_char = compose_ref(|s: &str| s.chars().next(), str::trim_start);
+///
+/// assert_eq!(first_char("  xyz"), Some('x'));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::compose_ref`]
+/// - extension on all functions: [`FnExt::compose_ref`]
+///
+/// [`fntools::compose_ref`]: crate::compose_ref
+/// [`FnExt::compose_ref`]: crate::unstable::FnExt::compose_ref
+#[inline]
+pub fn compose_ref<A, B, F, G>(f: F, g: G) -> ChainRef<G, F>
+where
+    A: ?Sized,
+    B: ?Sized,
+    F: for<'b> FnOnce<(&'b B,)>,
+    G: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+{
+    ChainRef::new(g, f)
+}
+
+/// Compose two projections (functions returning borrows of their arguments).
+///
+/// Takes functions `f: for<'b> &'b B -> &'b C` and
+/// `g: for<'a> &'a A -> &'a B` and returns `for<'a> |a: &'a A| -> &'a C`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::compose_proj;
+///
+/// let tail = compose_proj(|s: &str| s.get(1..).unwrap_or(""), str::trim);
+///
+/// assert_eq!(tail(" abc "), "bc");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::compose_proj`]
+/// - extension on all functions: [`FnExt::compose_proj`]
+///
+/// [`fntools::compose_proj`]: crate::compose_proj
+/// [`FnExt::compose_proj`]: crate::unstable::FnExt::compose_proj
+#[inline]
+pub fn compose_proj<A, B, C, F, G>(f: F, g: G) -> ChainRef<G, F>
+where
+    A: ?Sized,
+    B: ?Sized,
+    C: ?Sized,
+    F: for<'b> FnOnce<(&'b B,), Output = &'b C>,
+    G: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+{
+    ChainRef::new(g, f)
+}
+
+/// Represents composition `G ∘ F` of functions generic over lifetime of
+/// their argument.
+///
+/// For documentation see [`chain_ref`], [`chain_proj`], [`compose_ref`] and
+/// [`compose_proj`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct ChainRef<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> ChainRef<F, G> {
+    /// Creates chain of functions `f` and `g`.
+    ///
+    /// It's preferred to use [`chain_ref`] or [`chain_proj`] instead.
+    #[inline]
+    pub fn new(f: F, g: G) -> Self { ChainRef { f, g } }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ChainRef { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ChainRef { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<'a, A, B, F, G> FnOnce<(&'a A,)> for ChainRef<F, G>
+where
+    A: ?Sized,
+    B: ?Sized + 'a,
+    F: FnOnce<(&'a A,), Output = &'a B>,
+    G: FnOnce<(&'a B,)>,
+{
+    type Output = G::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (a,): (&'a A,)) -> Self::Output {
+        let ChainRef { f, g } = self;
+        g(f(a))
+    }
+}
+
+impl<'a, A, B, F, G> FnMut<(&'a A,)> for ChainRef<F, G>
+where
+    A: ?Sized,
+    B: ?Sized + 'a,
+    F: FnMut<(&'a A,), Output = &'a B>,
+    G: FnMut<(&'a B,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (&'a A,)) -> Self::Output {
+        let ChainRef { f, g } = self;
+        g(f(a))
+    }
+}
+
+impl<'a, A, B, F, G> Fn<(&'a A,)> for ChainRef<F, G>
+where
+    A: ?Sized,
+    B: ?Sized + 'a,
+    F: Fn<(&'a A,), Output = &'a B>,
+    G: Fn<(&'a B,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (&'a A,)) -> Self::Output {
+        let ChainRef { f, g } = self;
+        g(f(a))
+    }
+}
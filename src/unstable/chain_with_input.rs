@@ -0,0 +1,222 @@
+use crate::tuple::push::TuplePush;
+
+/// Chain two functions, giving the second one access to the original input.
+///
+/// Takes functions `f: &A -> B` and `g: &A, B -> C` and returns
+/// `|a: A| g(&a, f(&a))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_with_input;
+///
+/// let words = |s: &String| s.split_whitespace().count();
+/// let summary = |s: &String, n: usize| format!("{:?} has {} words", s, n);
+/// let fun = chain_with_input(words, summary);
+///
+/// assert_eq!(fun(String::from("a b c")), "\"a b c\" has 3 words");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_with_input`]
+/// - extension on all functions: [`FnExt::chain_with_input`]
+///
+/// [`fntools::chain_with_input`]: crate::chain_with_input
+/// [`FnExt::chain_with_input`]: crate::unstable::FnExt::chain_with_input
+#[inline]
+pub fn chain_with_input<F, G>(f: F, g: G) -> ChainWithInput<F, G> { ChainWithInput::new(f, g) }
+
+/// Represents chain of `F` and `G` where `G` also borrows the original input.
+///
+/// For documentation see [`chain_with_input`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct ChainWithInput<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> ChainWithInput<F, G> {
+    /// Creates chain of functions `f` and `g` where `g` also borrows the
+    /// original input.
+    ///
+    /// It's preferred to use [`chain_with_input`] instead.
+    #[inline]
+    pub fn new(f: F, g: G) -> Self { ChainWithInput { f, g } }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ChainWithInput { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ChainWithInput { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, B, C, F, G> FnOnce<(A,)> for ChainWithInput<F, G>
+where
+    F: for<'a> FnOnce<(&'a A,), Output = B>,
+    G: for<'a> FnOnce<(&'a A, B), Output = C>,
+{
+    type Output = C;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (a,): (A,)) -> Self::Output {
+        let ChainWithInput { f, g } = self;
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+impl<A, B, C, F, G> FnMut<(A,)> for ChainWithInput<F, G>
+where
+    F: for<'a> FnMut<(&'a A,), Output = B>,
+    G: for<'a> FnMut<(&'a A, B), Output = C>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (a,): (A,)) -> Self::Output {
+        let ChainWithInput { f, g } = self;
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+impl<A, B, C, F, G> Fn<(A,)> for ChainWithInput<F, G>
+where
+    F: for<'a> Fn<(&'a A,), Output = B>,
+    G: for<'a> Fn<(&'a A, B), Output = C>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (a,): (A,)) -> Self::Output {
+        let ChainWithInput { f, g } = self;
+        let b = f(&a);
+        g(&a, b)
+    }
+}
+
+/// Chain two functions, giving the second one the original input by value.
+///
+/// Takes functions `f: A, B, .. -> R` and `g: A, B, .., R -> C` and returns
+/// `|args..| g(args.clone().., f(args..))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::chain_with_input_owned;
+///
+/// let div = |a: i32, b: i32| a.checked_div(b);
+/// let explain = |a: i32, b: i32, res: Option<i32>| match res {
+///     Some(q) => format!("{} / {} = {}", a, b, q),
+///     None => format!("can't divide {} by {}", a, b),
+/// };
+/// let fun = chain_with_input_owned(div, explain);
+///
+/// assert_eq!(fun(7, 2), "7 / 2 = 3");
+/// assert_eq!(fun(7, 0), "can't divide 7 by 0");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::chain_with_input_owned`]
+/// - extension on all functions: [`FnExt::chain_with_input_owned`]
+/// - [`combinators::S`] (with the order of functions flipped)
+///
+/// [`fntools::chain_with_input_owned`]: crate::chain_with_input_owned
+/// [`FnExt::chain_with_input_owned`]: crate::unstable::FnExt::chain_with_input_owned
+/// [`combinators::S`]: crate::unstable::combinators::S
+#[inline]
+pub fn chain_with_input_owned<A, F, G>(f: F, g: G) -> ChainWithInputOwned<F, G>
+where
+    F: FnOnce<A>,
+    A: TuplePush<F::Output> + Clone,
+    G: FnOnce<A::Res>,
+{
+    ChainWithInputOwned::new(f, g)
+}
+
+/// Represents chain of `F` and `G` where `G` also gets the original input.
+///
+/// For documentation see [`chain_with_input_owned`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct ChainWithInputOwned<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> ChainWithInputOwned<F, G> {
+    /// Creates chain of functions `f` and `g` where `g` also gets the
+    /// original input.
+    ///
+    /// It's preferred to use [`chain_with_input_owned`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        A: TuplePush<F::Output> + Clone,
+        G: FnOnce<A::Res>,
+    {
+        ChainWithInputOwned { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let ChainWithInputOwned { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let ChainWithInputOwned { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for ChainWithInputOwned<F, G>
+where
+    F: FnOnce<A>,
+    A: TuplePush<F::Output> + Clone,
+    G: FnOnce<A::Res>,
+{
+    type Output = G::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let ChainWithInputOwned { f, g } = self;
+        let b = f.call_once(args.clone());
+        g.call_once(args.push(b))
+    }
+}
+
+impl<A, F, G> FnMut<A> for ChainWithInputOwned<F, G>
+where
+    F: FnMut<A>,
+    A: TuplePush<F::Output> + Clone,
+    G: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let ChainWithInputOwned { f, g } = self;
+        let b = f.call_mut(args.clone());
+        g.call_mut(args.push(b))
+    }
+}
+
+impl<A, F, G> Fn<A> for ChainWithInputOwned<F, G>
+where
+    F: Fn<A>,
+    A: TuplePush<F::Output> + Clone,
+    G: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let ChainWithInputOwned { f, g } = self;
+        let b = f.call(args.clone());
+        g.call(args.push(b))
+    }
+}
@@ -0,0 +1,424 @@
+/// Choice between two functions (`f ||| g`).
+///
+/// Takes functions `f` and `g` and returns function which applies `f` to `Ok`
+/// and `g` to `Err` (in other words something _like_
+/// `|r: Result<A, X>| match r { Ok(a) => f(a), Err(x) => g(x) }`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::choice;
+///
+/// let describe = choice(
+///     |n: i32| format!("number {}", n),
+///     |e: &str| format!("error: {}", e),
+/// );
+///
+/// assert_eq!(describe(Ok(17)), "number 17");
+/// assert_eq!(describe(Err("oops")), "error: oops");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::choice`]
+/// - extension on all functions: [`FnExt::choice`]
+///
+/// [`FnExt::choice`]: crate::unstable::FnExt::choice
+/// [`fntools::choice`]: crate::choice
+#[inline]
+pub fn choice<A, X, F, G>(f: F, g: G) -> Choice<F, G>
+where
+    F: FnOnce<(A,)>,
+    G: FnOnce<(X,), Output = F::Output>,
+{
+    Choice::new(f, g)
+}
+
+/// Represents choice between 2 functions `F ||| G`.
+///
+/// For documentation see [`choice`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Choice<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> Choice<F, G> {
+    /// Creates choice between functions `f` and `g`.
+    ///
+    /// It's preferred to use [`choice`] instead.
+    #[inline]
+    pub fn new<A, X>(f: F, g: G) -> Self
+    where
+        F: FnOnce<(A,)>,
+        G: FnOnce<(X,), Output = F::Output>,
+    {
+        Choice { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let Choice { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let Choice { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, X, F, G> FnOnce<(Result<A, X>,)> for Choice<F, G>
+where
+    F: FnOnce<(A,)>,
+    G: FnOnce<(X,), Output = F::Output>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Choice { f, g } = self;
+        match r {
+            Ok(a) => f(a),
+            Err(x) => g(x),
+        }
+    }
+}
+
+impl<A, X, F, G> FnMut<(Result<A, X>,)> for Choice<F, G>
+where
+    F: FnMut<(A,)>,
+    G: FnMut<(X,), Output = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Choice { f, g } = self;
+        match r {
+            Ok(a) => f(a),
+            Err(x) => g(x),
+        }
+    }
+}
+
+impl<A, X, F, G> Fn<(Result<A, X>,)> for Choice<F, G>
+where
+    F: Fn<(A,)>,
+    G: Fn<(X,), Output = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Choice { f, g } = self;
+        match r {
+            Ok(a) => f(a),
+            Err(x) => g(x),
+        }
+    }
+}
+
+/// Map both sides of a `Result` (`f +++ g`).
+///
+/// Takes functions `f` and `g` and returns function which applies `f` to `Ok`
+/// and `g` to `Err` keeping the branch (in other words something _like_
+/// `|r: Result<A, X>| match r { Ok(a) => Ok(f(a)), Err(x) => Err(g(x)) }`).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::bimap;
+///
+/// let fun = bimap(|n: i32| n * 2, str::len);
+///
+/// assert_eq!(fun(Ok(4)), Ok(8));
+/// assert_eq!(fun(Err("four")), Err(4));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::bimap`]
+/// - extension on all functions: [`FnExt::bimap`]
+///
+/// [`FnExt::bimap`]: crate::unstable::FnExt::bimap
+/// [`fntools::bimap`]: crate::bimap
+#[inline]
+pub fn bimap<A, X, F, G>(f: F, g: G) -> Bimap<F, G>
+where
+    F: FnOnce<(A,)>,
+    G: FnOnce<(X,)>,
+{
+    Bimap::new(f, g)
+}
+
+/// Represents mapping of both sides of a `Result`: `F +++ G`.
+///
+/// For documentation see [`bimap`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Bimap<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> Bimap<F, G> {
+    /// Creates function that maps `Ok` with `f` and `Err` with `g`.
+    ///
+    /// It's preferred to use [`bimap`] instead.
+    #[inline]
+    pub fn new<A, X>(f: F, g: G) -> Self
+    where
+        F: FnOnce<(A,)>,
+        G: FnOnce<(X,)>,
+    {
+        Bimap { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let Bimap { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let Bimap { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, X, F, G> FnOnce<(Result<A, X>,)> for Bimap<F, G>
+where
+    F: FnOnce<(A,)>,
+    G: FnOnce<(X,)>,
+{
+    type Output = Result<F::Output, G::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Bimap { f, g } = self;
+        match r {
+            Ok(a) => Ok(f(a)),
+            Err(x) => Err(g(x)),
+        }
+    }
+}
+
+impl<A, X, F, G> FnMut<(Result<A, X>,)> for Bimap<F, G>
+where
+    F: FnMut<(A,)>,
+    G: FnMut<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Bimap { f, g } = self;
+        match r {
+            Ok(a) => Ok(f(a)),
+            Err(x) => Err(g(x)),
+        }
+    }
+}
+
+impl<A, X, F, G> Fn<(Result<A, X>,)> for Bimap<F, G>
+where
+    F: Fn<(A,)>,
+    G: Fn<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Bimap { f, g } = self;
+        match r {
+            Ok(a) => Ok(f(a)),
+            Err(x) => Err(g(x)),
+        }
+    }
+}
+
+/// Map the left (`Ok`) side of a `Result`, passing `Err` through.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::left;
+///
+/// let fun = left(|n: i32| n + 1);
+///
+/// assert_eq!(fun(Ok::<_, ()>(1)), Ok(2));
+/// assert_eq!(fun(Err(())), Err(()));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::left`]
+/// - extension on all functions: [`FnExt::left`]
+///
+/// [`FnExt::left`]: crate::unstable::FnExt::left
+/// [`fntools::left`]: crate::left
+#[inline]
+pub fn left<A, F>(f: F) -> Left<F>
+where
+    F: FnOnce<(A,)>,
+{
+    Left::new(f)
+}
+
+/// Represents function `F` applied to the left (`Ok`) side of a `Result`.
+///
+/// For documentation see [`left`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Left<F>(F);
+
+impl<F> Left<F> {
+    /// Creates function that maps `Ok` with `f`.
+    ///
+    /// It's preferred to use [`left`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<(A,)>,
+    {
+        Left(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let Left(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let Left(f) = self;
+        f
+    }
+}
+
+impl<A, X, F> FnOnce<(Result<A, X>,)> for Left<F>
+where
+    F: FnOnce<(A,)>,
+{
+    type Output = Result<F::Output, X>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Left(f) = self;
+        r.map(f)
+    }
+}
+
+impl<A, X, F> FnMut<(Result<A, X>,)> for Left<F>
+where
+    F: FnMut<(A,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Left(f) = self;
+        r.map(f)
+    }
+}
+
+impl<A, X, F> Fn<(Result<A, X>,)> for Left<F>
+where
+    F: Fn<(A,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Left(f) = self;
+        r.map(f)
+    }
+}
+
+/// Map the right (`Err`) side of a `Result`, passing `Ok` through.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::right;
+///
+/// let fun = right(|e: String| e.len());
+///
+/// assert_eq!(fun(Ok::<i32, String>(1)), Ok(1));
+/// assert_eq!(fun(Err::<i32, _>(String::from("four"))), Err(4));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::right`]
+/// - extension on all functions: [`FnExt::right`]
+///
+/// [`FnExt::right`]: crate::unstable::FnExt::right
+/// [`fntools::right`]: crate::right
+#[inline]
+pub fn right<X, F>(f: F) -> Right<F>
+where
+    F: FnOnce<(X,)>,
+{
+    Right::new(f)
+}
+
+/// Represents function `F` applied to the right (`Err`) side of a `Result`.
+///
+/// For documentation see [`right`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Right<F>(F);
+
+impl<F> Right<F> {
+    /// Creates function that maps `Err` with `f`.
+    ///
+    /// It's preferred to use [`right`] instead.
+    #[inline]
+    pub fn new<X>(f: F) -> Self
+    where
+        F: FnOnce<(X,)>,
+    {
+        Right(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let Right(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let Right(f) = self;
+        f
+    }
+}
+
+impl<A, X, F> FnOnce<(Result<A, X>,)> for Right<F>
+where
+    F: FnOnce<(X,)>,
+{
+    type Output = Result<A, F::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Right(f) = self;
+        r.map_err(f)
+    }
+}
+
+impl<A, X, F> FnMut<(Result<A, X>,)> for Right<F>
+where
+    F: FnMut<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Right(f) = self;
+        r.map_err(f)
+    }
+}
+
+impl<A, X, F> Fn<(Result<A, X>,)> for Right<F>
+where
+    F: Fn<(X,)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (r,): (Result<A, X>,)) -> Self::Output {
+        let Right(f) = self;
+        r.map_err(f)
+    }
+}
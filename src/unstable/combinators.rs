@@ -0,0 +1,419 @@
+use crate::{
+    tuple::push::TuplePush,
+    unstable::{compose, dup, flip, Compose, Dup, Flip},
+    Const, Id,
+};
+
+/// B combinator (bluebird), composition.
+pub type B<F, G> = Compose<F, G>;
+
+/// C combinator (cardinal), flipping arguments.
+pub type C<F> = Flip<F>;
+
+/// K combinator (kestrel), constant function.
+pub type K<T> = Const<T>;
+
+/// I combinator (identity).
+pub type I = Id;
+
+/// W combinator (warbler), duplicating argument.
+pub type W<F> = Dup<F>;
+
+/// B combinator (bluebird), composition: `b(f, g)(x..) = f(g(x..))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::b;
+///
+/// let add_then_double = b(|x: i32| x * 2, |x: i32, y: i32| x + y);
+///
+/// assert_eq!(add_then_double(1, 2), 6);
+/// ```
+#[inline]
+pub fn b<A, F, G>(f: F, g: G) -> B<F, G>
+where
+    F: FnOnce<(G::Output,)>,
+    G: FnOnce<A>,
+{
+    compose(f, g)
+}
+
+/// C combinator (cardinal), flipping arguments: `c(f)(x, y, z) = f(z, y, x)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::c;
+///
+/// let fun = c(|a: i32, b: &str, c: char| format!("{}{}{}", a, b, c));
+///
+/// assert_eq!(fun('!', "-", 1), "1-!");
+/// ```
+#[inline]
+pub fn c<A, F>(f: F) -> C<F>
+where
+    F: FnOnce<A>,
+    A: crate::tuple::flip::FlipTuple,
+{
+    flip(f)
+}
+
+/// K combinator (kestrel), constant function: `k(x)(y..) = x`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::k;
+///
+/// let always_7 = k(7);
+///
+/// assert_eq!(always_7(), 7);
+/// assert_eq!(always_7("whatever", 1), 7);
+/// ```
+#[inline]
+pub fn k<T>(x: T) -> K<T> { Const(x) }
+
+/// I combinator (identity): `i()(x) = x`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::i;
+///
+/// let words: Vec<_> = vec!["a", "b"].into_iter().map(i()).collect();
+///
+/// assert_eq!(words, ["a", "b"]);
+/// ```
+#[inline]
+pub fn i() -> I { Id }
+
+/// W combinator (warbler), duplicating argument: `w(f)(x) = f(x, x)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::w;
+/// use std::ops::Mul;
+///
+/// let square = w(u32::mul);
+///
+/// assert_eq!(square(12), 144);
+/// ```
+#[inline]
+pub fn w<A, F>(f: F) -> W<F>
+where
+    F: FnOnce<(A, A)>,
+    A: Clone,
+{
+    dup(f)
+}
+
+/// S combinator (starling): `s(f, g)(x..) = f(x.., g(x..))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::s;
+///
+/// let fun = s(
+///     |a: i32, b: i32, sum: i32| format!("{} + {} = {}", a, b, sum),
+///     |a: i32, b: i32| a + b,
+/// );
+///
+/// assert_eq!(fun(1, 2), "1 + 2 = 3");
+/// ```
+#[inline]
+pub fn s<A, F, G>(f: F, g: G) -> S<F, G>
+where
+    G: FnOnce<A>,
+    A: TuplePush<G::Output> + Clone,
+    F: FnOnce<A::Res>,
+{
+    S::new(f, g)
+}
+
+/// Represents S combinator applied to `F` and `G`.
+///
+/// For documentation see [`s`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct S<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> S<F, G> {
+    /// Creates S combinator applied to `f` and `g`.
+    ///
+    /// It's preferred to use [`s`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        G: FnOnce<A>,
+        A: TuplePush<G::Output> + Clone,
+        F: FnOnce<A::Res>,
+    {
+        S { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let S { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let S { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for S<F, G>
+where
+    G: FnOnce<A>,
+    A: TuplePush<G::Output> + Clone,
+    F: FnOnce<A::Res>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let S { f, g } = self;
+        let gx = g.call_once(args.clone());
+        f.call_once(args.push(gx))
+    }
+}
+
+impl<A, F, G> FnMut<A> for S<F, G>
+where
+    G: FnMut<A>,
+    A: TuplePush<G::Output> + Clone,
+    F: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let S { f, g } = self;
+        let gx = g.call_mut(args.clone());
+        f.call_mut(args.push(gx))
+    }
+}
+
+impl<A, F, G> Fn<A> for S<F, G>
+where
+    G: Fn<A>,
+    A: TuplePush<G::Output> + Clone,
+    F: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let S { f, g } = self;
+        let gx = g.call(args.clone());
+        f.call(args.push(gx))
+    }
+}
+
+/// Φ combinator (phoenix), fork: `phi(f, g, h)(x..) = f(g(x..), h(x..))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::phi;
+///
+/// let min_max = phi(
+///     |min: i32, max: i32| (min, max),
+///     |a: i32, b: i32| a.min(b),
+///     |a: i32, b: i32| a.max(b),
+/// );
+///
+/// assert_eq!(min_max(7, 3), (3, 7));
+/// ```
+#[inline]
+pub fn phi<A, F, G, H>(f: F, g: G, h: H) -> Phi<F, G, H>
+where
+    G: FnOnce<A>,
+    H: FnOnce<A>,
+    A: Clone,
+    F: FnOnce<(G::Output, H::Output)>,
+{
+    Phi::new(f, g, h)
+}
+
+/// Represents Φ combinator applied to `F`, `G` and `H`.
+///
+/// For documentation see [`phi`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Phi<F, G, H> {
+    f: F,
+    g: G,
+    h: H,
+}
+
+impl<F, G, H> Phi<F, G, H> {
+    /// Creates Φ combinator applied to `f`, `g` and `h`.
+    ///
+    /// It's preferred to use [`phi`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G, h: H) -> Self
+    where
+        G: FnOnce<A>,
+        H: FnOnce<A>,
+        A: Clone,
+        F: FnOnce<(G::Output, H::Output)>,
+    {
+        Phi { f, g, h }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G, H) {
+        let Phi { f, g, h } = self;
+        (f, g, h)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G, &H) {
+        let Phi { f, g, h } = self;
+        (f, g, h)
+    }
+}
+
+impl<A, F, G, H> FnOnce<A> for Phi<F, G, H>
+where
+    G: FnOnce<A>,
+    H: FnOnce<A>,
+    A: Clone,
+    F: FnOnce<(G::Output, H::Output)>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Phi { f, g, h } = self;
+        f(g.call_once(args.clone()), h.call_once(args))
+    }
+}
+
+impl<A, F, G, H> FnMut<A> for Phi<F, G, H>
+where
+    G: FnMut<A>,
+    H: FnMut<A>,
+    A: Clone,
+    F: FnMut<(G::Output, H::Output)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Phi { f, g, h } = self;
+        f(g.call_mut(args.clone()), h.call_mut(args))
+    }
+}
+
+impl<A, F, G, H> Fn<A> for Phi<F, G, H>
+where
+    G: Fn<A>,
+    H: Fn<A>,
+    A: Clone,
+    F: Fn<(G::Output, H::Output)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Phi { f, g, h } = self;
+        f(g.call(args.clone()), h.call(args))
+    }
+}
+
+/// Ψ combinator (psi), `on`: `psi(f, g)(x, y) = f(g(x), g(y))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::combinators::psi;
+///
+/// let same_len = psi(|a: usize, b: usize| a == b, str::len);
+///
+/// assert!(same_len("abc", "xyz"));
+/// assert!(!same_len("abc", "xy"));
+/// ```
+#[inline]
+pub fn psi<X, F, G>(f: F, g: G) -> Psi<F, G>
+where
+    G: FnMut<(X,)>,
+    F: FnOnce<(G::Output, G::Output)>,
+{
+    Psi::new(f, g)
+}
+
+/// Represents Ψ combinator applied to `F` and `G`.
+///
+/// For documentation see [`psi`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Psi<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> Psi<F, G> {
+    /// Creates Ψ combinator applied to `f` and `g`.
+    ///
+    /// It's preferred to use [`psi`] instead.
+    #[inline]
+    pub fn new<X>(f: F, g: G) -> Self
+    where
+        G: FnMut<(X,)>,
+        F: FnOnce<(G::Output, G::Output)>,
+    {
+        Psi { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let Psi { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let Psi { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<X, F, G> FnOnce<(X, X)> for Psi<F, G>
+where
+    G: FnMut<(X,)>,
+    F: FnOnce<(G::Output, G::Output)>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (x, y): (X, X)) -> Self::Output {
+        let Psi { f, mut g } = self;
+        let gx = g(x);
+        f(gx, g(y))
+    }
+}
+
+impl<X, F, G> FnMut<(X, X)> for Psi<F, G>
+where
+    G: FnMut<(X,)>,
+    F: FnMut<(G::Output, G::Output)>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (x, y): (X, X)) -> Self::Output {
+        let Psi { f, g } = self;
+        let gx = g(x);
+        f(gx, g(y))
+    }
+}
+
+impl<X, F, G> Fn<(X, X)> for Psi<F, G>
+where
+    G: Fn<(X,)>,
+    F: Fn<(G::Output, G::Output)>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (x, y): (X, X)) -> Self::Output {
+        let Psi { f, g } = self;
+        f(g(x), g(y))
+    }
+}
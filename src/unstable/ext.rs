@@ -1,11 +1,30 @@
+use std::{convert::TryFrom, hash::Hash};
+
 use crate::{
-    tuple::{flip::FlipTuple, take::TupleTake},
+    fallible::{Fallible, FromResidual},
+    lazy::{Lazy, Thunk},
+    memo::{HashCache, Memoize},
+    tuple::{flip::FlipTuple, push::TuplePush, take::TupleTake},
     unstable::{
+        args::{dup, ignore_args, take_args, Dup, IgnoreArgs, TakeArgs},
         chain::{chain, Chain},
+        chain_flat::{chain_flat, ChainFlat},
+        chain_into::{chain_into, compose_into, try_chain_into, ChainInto, TryChainInto},
+        chain_ref::{chain_proj, chain_ref, compose_proj, compose_ref, ChainRef},
+        chain_with_input::{
+            chain_with_input, chain_with_input_owned, ChainWithInput, ChainWithInputOwned,
+        },
+        choice::{bimap, choice, left, right, Bimap, Choice, Left, Right},
         compose::{compose, Compose},
         curry::{curry, Curry},
-        flip::{flip, Flip},
+        flip::{flip, flip_both, flip_output, Flip, FlipBoth, FlipOutput},
+        lift::{lift_option, lift_result, lift_vec, LiftOption, LiftResult, LiftVec},
+        map_args::{map_args, MapArgs},
+        memo::memoize,
+        power::{power, Power},
+        predicate::{and, not, or, xor, And, Not, Or, Xor},
         supply::{supply, Supply},
+        try_chain::{try_chain, TryChain},
         unit::{unit, Unit},
         untuple::{untuple, Untuple},
     },
@@ -18,16 +37,60 @@ use crate::{
 /// - [`chain`] + [`untuple`]
 /// - [`compose`]
 /// - [`compose`] + [`untuple`]
+/// - [`chain_into`], [`try_chain_into`] and [`compose_into`]
+/// - [`chain_with_input`] and [`chain_with_input_owned`]
+/// - [`chain_ref`], [`chain_proj`], [`compose_ref`] and [`compose_proj`]
+/// - [`chain_flat`]
+/// - [`try_chain`]
+/// - [`choice`], [`bimap`], [`left`] and [`right`]
 /// - [`supply`]
-/// - [`flip`]
+/// - [`flip`], [`flip_output`] and [`flip_both`]
+/// - [`lift_option`], [`lift_result`] and [`lift_vec`]
+/// - [`memoize`]
+/// - [`power`]
+/// - [`Lazy`]
 /// - [`curry`]
+/// - [`ignore_args`], [`dup`] and [`take_args`]
+/// - [`map_args`]
+/// - [`and`], [`or`], [`not`] and [`xor`]
 ///
 /// [`chain`]: crate::unstable::chain
 /// [`untuple`]: crate::unstable::untuple
 /// [`compose`]: crate::unstable::compose
+/// [`chain_into`]: crate::unstable::chain_into
+/// [`try_chain_into`]: crate::unstable::try_chain_into
+/// [`compose_into`]: crate::unstable::compose_into
+/// [`chain_with_input`]: crate::unstable::chain_with_input
+/// [`chain_with_input_owned`]: crate::unstable::chain_with_input_owned
+/// [`chain_ref`]: crate::unstable::chain_ref
+/// [`chain_proj`]: crate::unstable::chain_proj
+/// [`compose_ref`]: crate::unstable::compose_ref
+/// [`compose_proj`]: crate::unstable::compose_proj
+/// [`chain_flat`]: crate::unstable::chain_flat
+/// [`try_chain`]: crate::unstable::try_chain
+/// [`choice`]: crate::unstable::choice
+/// [`bimap`]: crate::unstable::bimap
+/// [`left`]: crate::unstable::left
+/// [`right`]: crate::unstable::right
 /// [`supply`]: crate::unstable::supply
 /// [`flip`]: crate::unstable::flip
+/// [`flip_output`]: crate::unstable::flip_output
+/// [`flip_both`]: crate::unstable::flip_both
+/// [`lift_option`]: crate::unstable::lift_option
+/// [`lift_result`]: crate::unstable::lift_result
+/// [`lift_vec`]: crate::unstable::lift_vec
+/// [`memoize`]: crate::unstable::memoize
+/// [`power`]: crate::unstable::power
+/// [`Lazy`]: crate::lazy::Lazy
 /// [`curry`]: crate::unstable::curry
+/// [`ignore_args`]: crate::unstable::ignore_args
+/// [`dup`]: crate::unstable::dup
+/// [`take_args`]: crate::unstable::take_args
+/// [`map_args`]: crate::unstable::map_args
+/// [`and`]: crate::unstable::and
+/// [`or`]: crate::unstable::or
+/// [`not`]: crate::unstable::not
+/// [`xor`]: crate::unstable::xor
 pub trait FnExt<Args>: Sized {
     /// Chain two functions (`g ∘ self`)
     ///
@@ -147,6 +210,390 @@ pub trait FnExt<Args>: Sized {
         compose(untuple(self), g)
     }
 
+    /// Chain two functions converting output of `self` with `From`
+    /// (`g ∘ X::from ∘ self`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|a: u8, b: u8| a * b).chain_into(|x: u32| x << 16);
+    ///
+    /// assert_eq!(fun(2, 3), 6 << 16);
+    /// ```
+    ///
+    /// For more info see [`chain_into`]
+    ///
+    /// [`chain_into`]: crate::unstable::chain_into
+    #[inline]
+    fn chain_into<X, G>(self, g: G) -> ChainInto<Self, G, X>
+    where
+        Self: FnOnce<Args>,
+        X: From<Self::Output>,
+        G: FnOnce<(X,)>,
+    {
+        chain_into(self, g)
+    }
+
+    /// Chain two functions converting output of `self` with `TryFrom`
+    /// (`|args..| X::try_from(self(args..)).map(g)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|a: i32| a - 1).try_chain_into(|x: usize| vec![0; x]);
+    ///
+    /// assert_eq!(fun(3), Ok(vec![0, 0]));
+    /// assert!(fun(0).is_err());
+    /// ```
+    ///
+    /// For more info see [`try_chain_into`]
+    ///
+    /// [`try_chain_into`]: crate::unstable::try_chain_into
+    #[inline]
+    fn try_chain_into<X, G>(self, g: G) -> TryChainInto<Self, G, X>
+    where
+        Self: FnOnce<Args>,
+        X: TryFrom<Self::Output>,
+        G: FnOnce<(X,)>,
+    {
+        try_chain_into(self, g)
+    }
+
+    /// Compose two functions converting output of `g` with `From`
+    /// (`self ∘ X::from ∘ g`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|x: i64| x - 1).compose_into(|a: i32| a.abs());
+    ///
+    /// assert_eq!(fun(-5), 4i64);
+    /// ```
+    ///
+    /// For more info see [`compose_into`]
+    ///
+    /// [`compose_into`]: crate::unstable::compose_into
+    #[inline]
+    fn compose_into<A, X, G>(self, g: G) -> ChainInto<G, Self, X>
+    where
+        Self: FnOnce<(X,)>,
+        X: From<G::Output>,
+        G: FnOnce<A>,
+    {
+        compose_into(self, g)
+    }
+
+    /// Chain two functions giving `g` access to the original input
+    /// (`|a| g(&a, self(&a))`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let len = |v: &Vec<u8>| v.len();
+    /// let fun = len.chain_with_input(|v: &Vec<u8>, n: usize| v[n - 1]);
+    ///
+    /// assert_eq!(fun(vec![1, 2, 3]), 3);
+    /// ```
+    ///
+    /// For more info see [`chain_with_input`]
+    ///
+    /// [`chain_with_input`]: crate::unstable::chain_with_input
+    #[inline]
+    fn chain_with_input<G>(self, g: G) -> ChainWithInput<Self, G>
+    where
+        Self: FnOnce<Args>,
+    {
+        chain_with_input(self, g)
+    }
+
+    /// Chain two functions giving `g` the original input by value
+    /// (`|args..| g(args.clone().., self(args..))`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Mul;
+    ///
+    /// let fun = i32::mul.chain_with_input_owned(|a, b, p| format!("{}*{}={}", a, b, p));
+    ///
+    /// assert_eq!(fun(6, 7), "6*7=42");
+    /// ```
+    ///
+    /// For more info see [`chain_with_input_owned`]
+    ///
+    /// [`chain_with_input_owned`]: crate::unstable::chain_with_input_owned
+    #[inline]
+    fn chain_with_input_owned<G>(self, g: G) -> ChainWithInputOwned<Self, G>
+    where
+        Self: FnOnce<Args>,
+        Args: TuplePush<Self::Output> + Clone,
+        G: FnOnce<Args::Res>,
+    {
+        chain_with_input_owned(self, g)
+    }
+
+    /// Chain two functions where `self` returns borrow of its argument
+    /// (`for<'a> |a: &'a A| g(self(a))`).
+    ///
+    /// Note that `self` must be generic over the lifetime, e.g. a function
+    /// item (closures passed as receivers can't be inferred to return
+    /// borrows).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = str::trim.chain_ref(|s: &str| s.split(',').count());
+    ///
+    /// assert_eq!(fun(" a,b "), 2);
+    /// ```
+    ///
+    /// For more info see [`chain_ref`]
+    ///
+    /// [`chain_ref`]: crate::unstable::chain_ref
+    #[inline]
+    fn chain_ref<A, B, G>(self, g: G) -> ChainRef<Self, G>
+    where
+        A: ?Sized,
+        B: ?Sized,
+        Self: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+        G: for<'b> FnOnce<(&'b B,)>,
+    {
+        chain_ref(self, g)
+    }
+
+    /// Chain two projections (`for<'a> |a: &'a A| -> &'a C { g(self(a)) }`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = str::trim.chain_proj(|s: &str| s.split(',').next().unwrap());
+    ///
+    /// assert_eq!(fun(" a,b "), "a");
+    /// ```
+    ///
+    /// For more info see [`chain_proj`]
+    ///
+    /// [`chain_proj`]: crate::unstable::chain_proj
+    #[inline]
+    fn chain_proj<A, B, C, G>(self, g: G) -> ChainRef<Self, G>
+    where
+        A: ?Sized,
+        B: ?Sized,
+        C: ?Sized,
+        Self: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+        G: for<'b> FnOnce<(&'b B,), Output = &'b C>,
+    {
+        chain_proj(self, g)
+    }
+
+    /// Compose two functions where `g` returns borrow of its argument
+    /// (`for<'a> |a: &'a A| self(g(a))`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = str::len.compose_ref(str::trim);
+    ///
+    /// assert_eq!(fun(" ab "), 2);
+    /// ```
+    ///
+    /// For more info see [`compose_ref`]
+    ///
+    /// [`compose_ref`]: crate::unstable::compose_ref
+    #[inline]
+    fn compose_ref<A, B, G>(self, g: G) -> ChainRef<G, Self>
+    where
+        A: ?Sized,
+        B: ?Sized,
+        Self: for<'b> FnOnce<(&'b B,)>,
+        G: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+    {
+        compose_ref(self, g)
+    }
+
+    /// Compose two projections (`for<'a> |a: &'a A| -> &'a C { self(g(a)) }`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = str::trim_start.compose_proj(|v: &Vec<String>| v[0].as_str());
+    ///
+    /// assert_eq!(fun(&vec![String::from("  x")]), "x");
+    /// ```
+    ///
+    /// For more info see [`compose_proj`]
+    ///
+    /// [`compose_proj`]: crate::unstable::compose_proj
+    #[inline]
+    fn compose_proj<A, B, C, G>(self, g: G) -> ChainRef<G, Self>
+    where
+        A: ?Sized,
+        B: ?Sized,
+        C: ?Sized,
+        Self: for<'b> FnOnce<(&'b B,), Output = &'b C>,
+        G: for<'a> FnOnce<(&'a A,), Output = &'a B>,
+    {
+        compose_proj(self, g)
+    }
+
+    /// Chain two one-to-many functions (`self(..).into_iter().flat_map(g)`).
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let words = |s: &'static str| s.split_whitespace();
+    /// let fun = words.flat_chain(str::chars);
+    ///
+    /// assert_eq!(fun("ab c").collect::<String>(), "abc");
+    /// ```
+    ///
+    /// For more info see [`chain_flat`]
+    ///
+    /// [`chain_flat`]: crate::unstable::chain_flat
+    #[inline]
+    fn flat_chain<G>(self, g: G) -> ChainFlat<Self, G>
+    where
+        Self: FnOnce<Args>,
+        Self::Output: IntoIterator,
+        G: FnMut<(<Self::Output as IntoIterator>::Item,)>,
+        G::Output: IntoIterator,
+    {
+        chain_flat(self, g)
+    }
+
+    /// Chain two fallible functions (`g(self(..)?)`).
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let parse = |s: &str| s.parse::<u8>().ok();
+    /// let double = parse.and_then(|n: u8| n.checked_mul(2));
+    ///
+    /// assert_eq!(double("16"), Some(32));
+    /// assert_eq!(double("200"), None);
+    /// assert_eq!(double("two"), None);
+    /// ```
+    ///
+    /// For more info see [`try_chain`]
+    ///
+    /// [`try_chain`]: crate::unstable::try_chain
+    #[inline]
+    fn and_then<G>(self, g: G) -> TryChain<Self, G>
+    where
+        Self: FnOnce<Args>,
+        Self::Output: Fallible,
+        G: FnOnce<(<Self::Output as Fallible>::Output,)>,
+        G::Output: FromResidual<<Self::Output as Fallible>::Residual>,
+    {
+        try_chain(self, g)
+    }
+
+    /// Choice between two functions (`self ||| g`): `self` handles `Ok`, `g`
+    /// handles `Err`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let or_zero = (|n: i32| n).choice(|_: std::num::ParseIntError| 0);
+    ///
+    /// assert_eq!(or_zero("42".parse()), 42);
+    /// assert_eq!(or_zero("forty two".parse()), 0);
+    /// ```
+    ///
+    /// For more info see [`choice`]
+    ///
+    /// [`choice`]: crate::unstable::choice
+    #[inline]
+    fn choice<A, X, G>(self, g: G) -> Choice<Self, G>
+    where
+        Self: FnOnce<(A,)>,
+        G: FnOnce<(X,), Output = Self::Output>,
+    {
+        choice(self, g)
+    }
+
+    /// Map both sides of a `Result` (`self +++ g`): `self` maps `Ok`, `g` maps
+    /// `Err`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|n: i32| n * 2).bimap(str::len);
+    ///
+    /// assert_eq!(fun(Ok(4)), Ok(8));
+    /// assert_eq!(fun(Err("four")), Err(4));
+    /// ```
+    ///
+    /// For more info see [`bimap`]
+    ///
+    /// [`bimap`]: crate::unstable::bimap
+    #[inline]
+    fn bimap<A, X, G>(self, g: G) -> Bimap<Self, G>
+    where
+        Self: FnOnce<(A,)>,
+        G: FnOnce<(X,)>,
+    {
+        bimap(self, g)
+    }
+
+    /// Apply `self` to the left (`Ok`) side of a `Result`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|n: i32| n + 1).left();
+    ///
+    /// assert_eq!(fun(Ok::<_, ()>(1)), Ok(2));
+    /// assert_eq!(fun(Err(())), Err(()));
+    /// ```
+    ///
+    /// For more info see [`left`]
+    ///
+    /// [`left`]: crate::unstable::left
+    #[inline]
+    fn left<A>(self) -> Left<Self>
+    where
+        Self: FnOnce<(A,)>,
+    {
+        left(self)
+    }
+
+    /// Apply `self` to the right (`Err`) side of a `Result`.
+    ///
+    /// # Examples:
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|e: String| e.len()).right();
+    ///
+    /// assert_eq!(fun(Ok::<i32, String>(1)), Ok(1));
+    /// assert_eq!(fun(Err::<i32, _>(String::from("four"))), Err(4));
+    /// ```
+    ///
+    /// For more info see [`right`]
+    ///
+    /// [`right`]: crate::unstable::right
+    #[inline]
+    fn right<X>(self) -> Right<Self>
+    where
+        Self: FnOnce<(X,)>,
+    {
+        right(self)
+    }
+
     /// Supply argument to function.
     ///
     /// ## Example
@@ -191,6 +638,390 @@ pub trait FnExt<Args>: Sized {
         flip(self)
     }
 
+    /// Flips output of `self` (which must be a tuple).
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|s: &str| (s.len(), s.to_uppercase())).flip_output();
+    ///
+    /// assert_eq!(fun("abc"), (String::from("ABC"), 3));
+    /// ```
+    ///
+    /// For more info see [`flip_output`]
+    ///
+    /// [`flip_output`]: crate::unstable::flip_output
+    #[inline]
+    fn flip_output(self) -> FlipOutput<Self>
+    where
+        Self: FnOnce<Args>,
+        Self::Output: FlipTuple,
+    {
+        flip_output(self)
+    }
+
+    /// Flips both argument order and output (which must be a tuple) of `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|a: u8, b: char| (a, b)).flip_both();
+    ///
+    /// assert_eq!(fun('x', 1), ('x', 1));
+    /// ```
+    ///
+    /// For more info see [`flip_both`]
+    ///
+    /// [`flip_both`]: crate::unstable::flip_both
+    #[inline]
+    fn flip_both(self) -> FlipBoth<Self>
+    where
+        Self: FnOnce<Args>,
+        Args: FlipTuple,
+        Self::Output: FlipTuple,
+    {
+        flip_both(self)
+    }
+
+    /// Lift `self` into `Option`s.
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let add = i32::add.lift_option();
+    ///
+    /// assert_eq!(add(Some(1), Some(2)), Some(3));
+    /// assert_eq!(add(Some(1), None), None);
+    /// ```
+    ///
+    /// For more info see [`lift_option`]
+    ///
+    /// [`lift_option`]: crate::unstable::lift_option
+    #[inline]
+    fn lift_option(self) -> LiftOption<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        lift_option(self)
+    }
+
+    /// Lift `self` into `Result`s.
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let add = i32::add.lift_result();
+    ///
+    /// assert_eq!(add(Ok::<_, ()>(1), Ok(2)), Ok(3));
+    /// assert_eq!(add(Ok(1), Err(())), Err(()));
+    /// ```
+    ///
+    /// For more info see [`lift_result`]
+    ///
+    /// [`lift_result`]: crate::unstable::lift_result
+    #[inline]
+    fn lift_result(self) -> LiftResult<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        lift_result(self)
+    }
+
+    /// Lift `self` into iterables (cartesian product).
+    ///
+    /// # Example
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let add = i32::add.lift_vec();
+    ///
+    /// assert_eq!(add(vec![1, 2], vec![10, 20]), [11, 21, 12, 22]);
+    /// ```
+    ///
+    /// For more info see [`lift_vec`]
+    ///
+    /// [`lift_vec`]: crate::unstable::lift_vec
+    #[inline]
+    fn lift_vec(self) -> LiftVec<Self>
+    where
+        Self: FnOnce<Args>,
+    {
+        lift_vec(self)
+    }
+
+    /// Ignore all arguments, so `self` can be called with any arguments.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let fun = (|| 1).ignore_args();
+    ///
+    /// assert_eq!(fun("a", 'b'), 1);
+    /// ```
+    ///
+    /// For more info see [`ignore_args`]
+    ///
+    /// [`ignore_args`]: crate::unstable::ignore_args
+    #[inline]
+    fn ignore_args(self) -> IgnoreArgs<Self>
+    where
+        Self: FnOnce<()>,
+    {
+        ignore_args(self)
+    }
+
+    /// Duplicate argument of `self` (`|a| self(a.clone(), a)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let double = i32::add.dup();
+    ///
+    /// assert_eq!(double(21), 42);
+    /// ```
+    ///
+    /// For more info see [`dup`]
+    ///
+    /// [`dup`]: crate::unstable::dup
+    #[inline]
+    fn dup<A>(self) -> Dup<Self>
+    where
+        Self: FnOnce<(A, A)>,
+        A: Clone,
+    {
+        dup(self)
+    }
+
+    /// Call `self` with only first `N` arguments, dropping the others.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let first = (|a: i32| a).take_args::<1>();
+    ///
+    /// assert_eq!(first(1, "ignored", ()), 1);
+    /// ```
+    ///
+    /// For more info see [`take_args`]
+    ///
+    /// [`take_args`]: crate::unstable::take_args
+    #[inline]
+    fn take_args<const N: usize>(self) -> TakeArgs<Self, N>
+    where
+        Self: FnOnce<Args>,
+    {
+        take_args(self)
+    }
+
+    /// Map each argument of `self` with its own function
+    /// (`|a, b, ..| self(g1(a), g2(b), ..)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::ops::Add;
+    ///
+    /// let add_lens = usize::add.map_args((str::len, |v: Vec<u8>| v.len()));
+    ///
+    /// assert_eq!(add_lens("abc", vec![1, 2]), 5);
+    /// ```
+    ///
+    /// For more info see [`map_args`]
+    ///
+    /// [`map_args`]: crate::unstable::map_args
+    #[inline]
+    fn map_args<Gs>(self, gs: Gs) -> MapArgs<Self, Gs>
+    where
+        Self: FnOnce<Args>,
+    {
+        map_args(self, gs)
+    }
+
+    /// Logical and of predicates (`|args..| self(args..) && p(args..)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let even = |x: &i32| x % 2 == 0;
+    /// let v: Vec<_> = (0..10).filter(even.and(|x: &i32| *x > 4)).collect();
+    ///
+    /// assert_eq!(v, [6, 8]);
+    /// ```
+    ///
+    /// For more info see [`and`]
+    ///
+    /// [`and`]: crate::unstable::and
+    #[inline]
+    fn and<P>(self, p: P) -> And<Self, P>
+    where
+        Self: FnOnce<Args, Output = bool>,
+    {
+        and(self, p)
+    }
+
+    /// Logical or of predicates (`|args..| self(args..) || p(args..)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let zero = |x: &i32| *x == 0;
+    /// let v: Vec<_> = (-2..=2).filter(zero.or(|x: &i32| *x < 0)).collect();
+    ///
+    /// assert_eq!(v, [-2, -1, 0]);
+    /// ```
+    ///
+    /// For more info see [`or`]
+    ///
+    /// [`or`]: crate::unstable::or
+    #[inline]
+    fn or<P>(self, p: P) -> Or<Self, P>
+    where
+        Self: FnOnce<Args, Output = bool>,
+    {
+        or(self, p)
+    }
+
+    /// Logical negation of predicate (`|args..| !self(args..)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let empty = |s: &&str| s.is_empty();
+    /// let v: Vec<_> = vec!["a", "", "b"].into_iter().filter(empty.not()).collect();
+    ///
+    /// assert_eq!(v, ["a", "b"]);
+    /// ```
+    ///
+    /// For more info see [`not`]
+    ///
+    /// [`not`]: crate::unstable::not
+    #[inline]
+    fn not(self) -> Not<Self>
+    where
+        Self: FnOnce<Args, Output = bool>,
+    {
+        not(self)
+    }
+
+    /// Logical exclusive or of predicates (`|args..| self(args..) ^
+    /// p(args..)`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let neq = (|a: &bool, _: &bool| *a).xor(|_: &bool, b: &bool| *b);
+    ///
+    /// assert!(neq(&true, &false));
+    /// assert!(!neq(&true, &true));
+    /// ```
+    ///
+    /// For more info see [`xor`]
+    ///
+    /// [`xor`]: crate::unstable::xor
+    #[inline]
+    fn xor<P>(self, p: P) -> Xor<Self, P>
+    where
+        Self: FnOnce<Args, Output = bool>,
+    {
+        xor(self, p)
+    }
+
+    /// Memoize function, caching results by the tuple of arguments.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let pow = (|base: u64, exp: u32| base.pow(exp)).memoize();
+    ///
+    /// assert_eq!(pow(2, 10), 1024);
+    /// assert_eq!(pow(2, 10), 1024);
+    /// assert_eq!(pow.stats().hits, 1);
+    /// ```
+    ///
+    /// For more info see [`memoize`]
+    ///
+    /// [`memoize`]: crate::unstable::memoize
+    #[inline]
+    fn memoize(self) -> Memoize<Self, HashCache<Args, Self::Output>>
+    where
+        Self: Fn<Args>,
+        Args: Hash + Eq + Clone,
+        Self::Output: Clone,
+    {
+        memoize(self)
+    }
+
+    /// Apply `self` `n` times (`selfⁿ`).
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    ///
+    /// let halve = |x: u32| x / 2;
+    /// let div_8 = halve.power(3);
+    ///
+    /// assert_eq!(div_8(100), 12);
+    /// ```
+    ///
+    /// For more info see [`power`]
+    ///
+    /// [`power`]: crate::unstable::power
+    #[inline]
+    fn power<T>(self, n: usize) -> Power<Self>
+    where
+        Self: FnMut<(T,), Output = T>,
+    {
+        power(self, n)
+    }
+
+    /// Bind all arguments, deferring the call until the result is needed.
+    ///
+    /// ## Examples
+    /// ```
+    /// use fntools::unstable::FnExt;
+    /// use std::cell::Cell;
+    ///
+    /// let calls = Cell::new(0);
+    /// let add = |a: i32, b: i32| {
+    ///     calls.set(calls.get() + 1);
+    ///     a + b
+    /// };
+    /// let sum = add.thunk((2, 3));
+    ///
+    /// assert_eq!(calls.get(), 0);
+    /// assert_eq!(*sum, 5);
+    /// assert_eq!(*sum, 5);
+    /// assert_eq!(calls.get(), 1);
+    /// ```
+    ///
+    /// For more info see [`Lazy`]
+    ///
+    /// [`Lazy`]: crate::lazy::Lazy
+    #[inline]
+    fn thunk<'a>(self, args: Args) -> Thunk<'a, Self::Output>
+    where
+        Self: FnOnce<Args> + 'a,
+        Args: 'a,
+    {
+        Lazy::new(move || self.call_once(args)).boxed()
+    }
+
     /// Curring.
     ///
     /// ## Examples
@@ -0,0 +1,249 @@
+use std::{
+    fmt::{Debug, Error, Formatter},
+    hash::Hash,
+    marker::PhantomData,
+};
+
+use crate::{
+    memo::{HashCache, Memoize, Stats},
+    tuple::append::TupleAppend,
+};
+
+/// Fixed-point combinator for functions of any number of arguments.
+///
+/// Takes function `f: recur, A, B, .. -> R` and returns function
+/// `A, B, .. -> R` which calls `f` passing itself as `recur`.
+///
+/// **Note**: type of `recur` (`&dyn Fn(A, B, ..) -> R`) must be specified
+/// explicitly, rustc can't infer it.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::fix;
+///
+/// let ackermann = fix(
+///     |recur: &dyn Fn(u64, u64) -> u64, m: u64, n: u64| match (m, n) {
+///         (0, n) => n + 1,
+///         (m, 0) => recur(m - 1, 1),
+///         (m, n) => recur(m - 1, recur(m, n - 1)),
+///     },
+/// );
+///
+/// assert_eq!(ackermann(2, 3), 9);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::fix`]
+/// - memoized version of this function: [`memo_fix`]
+///
+/// [`fntools::fix`]: crate::fix
+/// [`memo_fix`]: crate::unstable::memo_fix
+#[inline]
+pub fn fix<R, F>(f: F) -> Fix<F, R> { Fix::new(f) }
+
+/// Represents fixed point of the function `F`.
+///
+/// For documentation see [`fix`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+pub struct Fix<F, R> {
+    f: F,
+    marker: PhantomData<fn() -> R>,
+}
+
+impl<F, R> Fix<F, R> {
+    /// Creates fixed point of the function `f`.
+    ///
+    /// It's preferred to use [`fix`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self {
+        Fix {
+            f,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let Fix { f, marker: _ } = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let Fix { f, marker: _ } = self;
+        f
+    }
+}
+
+impl<A, R, F> FnOnce<A> for Fix<F, R>
+where
+    A: for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> FnMut<A> for Fix<F, R>
+where
+    A: for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> Fn<A> for Fix<F, R>
+where
+    A: for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        self.f.call(args.append(self as &dyn Fn<A, Output = R>))
+    }
+}
+
+impl<F, R> Debug for Fix<F, R>
+where
+    F: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result<(), Error> {
+        f.debug_struct("Fix").field("f", &self.f).finish()
+    }
+}
+
+impl<F, R> Clone for Fix<F, R>
+where
+    F: Clone,
+{
+    #[inline]
+    fn clone(&self) -> Self { Fix::new(self.f.clone()) }
+}
+
+impl<F, R> Copy for Fix<F, R> where F: Copy {}
+
+/// Memoized fixed-point combinator for functions of any number of arguments.
+///
+/// Like [`fix`], but caches results of all (including recursive) calls keyed
+/// by the tuple of arguments.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::memo_fix;
+///
+/// // number of paths in `m x n` grid
+/// let paths = memo_fix(|recur: &dyn Fn(u64, u64) -> u64, m: u64, n: u64| {
+///     if m == 0 || n == 0 {
+///         1
+///     } else {
+///         recur(m - 1, n) + recur(m, n - 1)
+///     }
+/// });
+///
+/// assert_eq!(paths(16, 16), 601_080_390);
+/// // every pair except `(0, 0)` was computed exactly once
+/// assert_eq!(paths.stats().misses, 17 * 17 - 1);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::memo_fix`]
+///
+/// [`fntools::memo_fix`]: crate::memo_fix
+#[inline]
+pub fn memo_fix<A, R, F>(f: F) -> MemoFix<F, A, R>
+where
+    A: Hash + Eq + Clone,
+    R: Clone,
+{
+    MemoFix::new(f)
+}
+
+/// Represents memoized fixed point of the function `F`.
+///
+/// For documentation see [`memo_fix`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug)]
+pub struct MemoFix<F, A, R> {
+    memo: Memoize<F, HashCache<A, R>>,
+}
+
+impl<F, A, R> MemoFix<F, A, R> {
+    /// Creates memoized fixed point of the function `f`.
+    ///
+    /// It's preferred to use [`memo_fix`] instead.
+    #[inline]
+    pub fn new(f: F) -> Self
+    where
+        A: Hash + Eq + Clone,
+        R: Clone,
+    {
+        MemoFix {
+            memo: Memoize::with_cache(f, HashCache::new()),
+        }
+    }
+
+    /// Returns cache statistics.
+    #[inline]
+    pub fn stats(&self) -> Stats { self.memo.stats() }
+
+    /// Removes all cached results and resets statistics.
+    #[inline]
+    pub fn clear(&self)
+    where
+        A: Hash + Eq,
+    {
+        self.memo.clear()
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let MemoFix { memo } = self;
+        memo.into_inner().0
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F { self.memo.as_inner() }
+}
+
+impl<A, R, F> FnOnce<A> for MemoFix<F, A, R>
+where
+    A: Hash + Eq + Clone + for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    R: Clone,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    type Output = R;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> FnMut<A> for MemoFix<F, A, R>
+where
+    A: Hash + Eq + Clone + for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    R: Clone,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, R, F> Fn<A> for MemoFix<F, A, R>
+where
+    A: Hash + Eq + Clone + for<'a> TupleAppend<&'a dyn Fn<A, Output = R>>,
+    R: Clone,
+    F: for<'a> Fn<<A as TupleAppend<&'a dyn Fn<A, Output = R>>>::Res, Output = R>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let recur = self as &dyn Fn<A, Output = R>;
+        self.memo
+            .get_or_call(args, |f, args| f.call(args.append(recur)))
+    }
+}
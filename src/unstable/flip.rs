@@ -94,3 +94,209 @@ where
         res
     }
 }
+
+/// Flips output of `self` (which must be a tuple).
+///
+/// # Example
+/// ```
+/// use fntools::unstable::flip_output;
+///
+/// let fun = flip_output(|a: i32, b: i32| (a + b, a - b, a * b));
+///
+/// assert_eq!(fun(3, 2), (6, 1, 5));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::flip_output`]
+/// - extension on all functions: [`FnExt::flip_output`]
+/// - [`flip_both`]
+///
+/// [`fntools::flip_output`]: crate::flip_output
+/// [`FnExt::flip_output`]: crate::unstable::FnExt::flip_output
+#[inline]
+pub fn flip_output<A, F>(f: F) -> FlipOutput<F>
+where
+    F: FnOnce<A>,
+    F::Output: FlipTuple,
+{
+    FlipOutput::new(f)
+}
+
+/// Represents function `F` with flipped output.
+///
+/// For documentation see [`flip_output`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct FlipOutput<F>(F);
+
+impl<F> FlipOutput<F> {
+    /// Creates version of the function `f` with flipped output.
+    ///
+    /// It's preferred to use [`flip_output`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+        F::Output: FlipTuple,
+    {
+        FlipOutput(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let FlipOutput(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let FlipOutput(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for FlipOutput<F>
+where
+    F: FnOnce<A>,
+    F::Output: FlipTuple,
+{
+    type Output = <F::Output as FlipTuple>::Res;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let FlipOutput(f) = self;
+        f.call_once(args).flip()
+    }
+}
+
+impl<A, F> FnMut<A> for FlipOutput<F>
+where
+    F: FnMut<A>,
+    F::Output: FlipTuple,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let FlipOutput(f) = self;
+        f.call_mut(args).flip()
+    }
+}
+
+impl<A, F> Fn<A> for FlipOutput<F>
+where
+    F: Fn<A>,
+    F::Output: FlipTuple,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let FlipOutput(f) = self;
+        f.call(args).flip()
+    }
+}
+
+/// Flips both argument order and output (which must be a tuple) of `self`.
+///
+/// # Example
+/// ```
+/// use fntools::unstable::flip_both;
+///
+/// let fun = flip_both(|name: &str, age: u32, admin: bool| (name.len(), age, admin));
+///
+/// assert_eq!(fun(true, 42, "Ann"), (true, 42, 3));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::flip_both`]
+/// - extension on all functions: [`FnExt::flip_both`]
+/// - [`flip`], [`flip_output`]
+///
+/// [`fntools::flip_both`]: crate::flip_both
+/// [`FnExt::flip_both`]: crate::unstable::FnExt::flip_both
+#[inline]
+pub fn flip_both<A, F>(f: F) -> FlipBoth<F>
+where
+    F: FnOnce<A>,
+    A: FlipTuple,
+    F::Output: FlipTuple,
+{
+    FlipBoth::new(f)
+}
+
+/// Represents function `F` with flipped argument order and output.
+///
+/// For documentation see [`flip_both`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct FlipBoth<F>(F);
+
+impl<F> FlipBoth<F> {
+    /// Creates version of the function `f` with flipped argument order and
+    /// output.
+    ///
+    /// It's preferred to use [`flip_both`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+        A: FlipTuple,
+        F::Output: FlipTuple,
+    {
+        FlipBoth(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let FlipBoth(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let FlipBoth(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for FlipBoth<F>
+where
+    F: FnOnce<A::Res>,
+    A: FlipTuple,
+    F::Output: FlipTuple,
+{
+    type Output = <F::Output as FlipTuple>::Res;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let FlipBoth(f) = self;
+        f.call_once(args.flip()).flip()
+    }
+}
+
+impl<A, F> FnMut<A> for FlipBoth<F>
+where
+    F: FnMut<A::Res>,
+    A: FlipTuple,
+    F::Output: FlipTuple,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let FlipBoth(f) = self;
+        f.call_mut(args.flip()).flip()
+    }
+}
+
+impl<A, F> Fn<A> for FlipBoth<F>
+where
+    F: Fn<A::Res>,
+    A: FlipTuple,
+    F::Output: FlipTuple,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let FlipBoth(f) = self;
+        f.call(args.flip()).flip()
+    }
+}
@@ -0,0 +1,329 @@
+use crate::tuple::{
+    cartesian::TupleCartesian,
+    transpose::{TransposeOption, TransposeResult},
+};
+
+/// Lift function into `Option`s.
+///
+/// Takes function `f: A, B, .. -> R` and returns something _like_
+/// `|a: Option<A>, b: Option<B>, ..| Some(f(a?, b?, ..))`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::lift_option;
+///
+/// let add = lift_option(|a: i32, b: i32, c: i32| a + b + c);
+///
+/// assert_eq!(add(Some(1), Some(2), Some(3)), Some(6));
+/// assert_eq!(add(Some(1), None, Some(3)), None);
+/// ```
+///
+/// See also:
+/// - stable versions of this function: [`fntools::lift_option`],
+///   [`fntools::lift_option2`], ...
+/// - extension on all functions: [`FnExt::lift_option`]
+///
+/// [`FnExt::lift_option`]: crate::unstable::FnExt::lift_option
+/// [`fntools::lift_option`]: crate::lift_option
+/// [`fntools::lift_option2`]: crate::lift_option2
+#[inline]
+pub fn lift_option<A, F>(f: F) -> LiftOption<F>
+where
+    F: FnOnce<A>,
+{
+    LiftOption::new(f)
+}
+
+/// Represents function `F` lifted into `Option`s.
+///
+/// For documentation see [`lift_option`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct LiftOption<F>(F);
+
+impl<F> LiftOption<F> {
+    /// Creates version of the function `f` lifted into `Option`s.
+    ///
+    /// It's preferred to use [`lift_option`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+    {
+        LiftOption(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let LiftOption(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let LiftOption(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for LiftOption<F>
+where
+    A: TransposeOption,
+    F: FnOnce<A::Res>,
+{
+    type Output = Option<F::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let LiftOption(f) = self;
+        args.transpose_option().map(|args| f.call_once(args))
+    }
+}
+
+impl<A, F> FnMut<A> for LiftOption<F>
+where
+    A: TransposeOption,
+    F: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let LiftOption(f) = self;
+        args.transpose_option().map(|args| f.call_mut(args))
+    }
+}
+
+impl<A, F> Fn<A> for LiftOption<F>
+where
+    A: TransposeOption,
+    F: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let LiftOption(f) = self;
+        args.transpose_option().map(|args| f.call(args))
+    }
+}
+
+/// Lift function into `Result`s.
+///
+/// Takes function `f: A, B, .. -> R` and returns something _like_
+/// `|a: Result<A, E>, b: Result<B, E>, ..| Ok(f(a?, b?, ..))`, i.e. the first
+/// error short-circuits.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::lift_result;
+///
+/// let parse = |s: &str| s.parse::<i32>();
+/// let add = lift_result(|a: i32, b: i32, c: i32| a + b + c);
+///
+/// assert_eq!(add(parse("1"), parse("2"), parse("3")), Ok(6));
+/// assert!(add(parse("1"), parse("two"), parse("3")).is_err());
+/// ```
+///
+/// See also:
+/// - stable versions of this function: [`fntools::lift_result`],
+///   [`fntools::lift_result2`], ...
+/// - extension on all functions: [`FnExt::lift_result`]
+///
+/// [`FnExt::lift_result`]: crate::unstable::FnExt::lift_result
+/// [`fntools::lift_result`]: crate::lift_result
+/// [`fntools::lift_result2`]: crate::lift_result2
+#[inline]
+pub fn lift_result<A, F>(f: F) -> LiftResult<F>
+where
+    F: FnOnce<A>,
+{
+    LiftResult::new(f)
+}
+
+/// Represents function `F` lifted into `Result`s.
+///
+/// For documentation see [`lift_result`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct LiftResult<F>(F);
+
+impl<F> LiftResult<F> {
+    /// Creates version of the function `f` lifted into `Result`s.
+    ///
+    /// It's preferred to use [`lift_result`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+    {
+        LiftResult(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let LiftResult(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let LiftResult(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for LiftResult<F>
+where
+    A: TransposeResult,
+    F: FnOnce<A::Res>,
+{
+    type Output = Result<F::Output, A::Err>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let LiftResult(f) = self;
+        args.transpose_result().map(|args| f.call_once(args))
+    }
+}
+
+impl<A, F> FnMut<A> for LiftResult<F>
+where
+    A: TransposeResult,
+    F: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let LiftResult(f) = self;
+        args.transpose_result().map(|args| f.call_mut(args))
+    }
+}
+
+impl<A, F> Fn<A> for LiftResult<F>
+where
+    A: TransposeResult,
+    F: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let LiftResult(f) = self;
+        args.transpose_result().map(|args| f.call(args))
+    }
+}
+
+/// Lift function into iterables (cartesian product).
+///
+/// Takes function `f: A, B, .. -> R` and returns function which takes
+/// iterables of `A`, `B`, .. and returns `Vec<R>` of results of calling `f`
+/// with every combination of the elements (see [`TupleCartesian`]).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::lift_vec;
+///
+/// let concat = |a: i32, b: char, c: bool| format!("{}{}{}", a, b, c as u8);
+/// let fun = lift_vec(concat);
+///
+/// assert_eq!(
+///     fun(vec![1, 2], vec!['a', 'b'], vec![true]),
+///     ["1a1", "1b1", "2a1", "2b1"]
+/// );
+/// ```
+///
+/// See also:
+/// - stable versions of this function: [`fntools::lift_vec`],
+///   [`fntools::lift_vec2`], ...
+/// - extension on all functions: [`FnExt::lift_vec`]
+///
+/// [`FnExt::lift_vec`]: crate::unstable::FnExt::lift_vec
+/// [`fntools::lift_vec`]: crate::lift_vec
+/// [`fntools::lift_vec2`]: crate::lift_vec2
+/// [`TupleCartesian`]: crate::tuple::cartesian::TupleCartesian
+#[inline]
+pub fn lift_vec<A, F>(f: F) -> LiftVec<F>
+where
+    F: FnOnce<A>,
+{
+    LiftVec::new(f)
+}
+
+/// Represents function `F` lifted into iterables.
+///
+/// For documentation see [`lift_vec`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct LiftVec<F>(F);
+
+impl<F> LiftVec<F> {
+    /// Creates version of the function `f` lifted into iterables.
+    ///
+    /// It's preferred to use [`lift_vec`] instead.
+    #[inline]
+    pub fn new<A>(f: F) -> Self
+    where
+        F: FnOnce<A>,
+    {
+        LiftVec(f)
+    }
+
+    /// Returns inner function.
+    #[inline]
+    pub fn into_inner(self) -> F {
+        let LiftVec(f) = self;
+        f
+    }
+
+    /// Returns reference to inner function.
+    #[inline]
+    pub fn as_inner(&self) -> &F {
+        let LiftVec(f) = self;
+        f
+    }
+}
+
+impl<A, F> FnOnce<A> for LiftVec<F>
+where
+    A: TupleCartesian,
+    F: FnMut<A::Res>,
+{
+    type Output = Vec<F::Output>;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let LiftVec(mut f) = self;
+        args.cartesian()
+            .into_iter()
+            .map(|args| f.call_mut(args))
+            .collect()
+    }
+}
+
+impl<A, F> FnMut<A> for LiftVec<F>
+where
+    A: TupleCartesian,
+    F: FnMut<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let LiftVec(f) = self;
+        args.cartesian()
+            .into_iter()
+            .map(|args| f.call_mut(args))
+            .collect()
+    }
+}
+
+impl<A, F> Fn<A> for LiftVec<F>
+where
+    A: TupleCartesian,
+    F: Fn<A::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let LiftVec(f) = self;
+        args.cartesian()
+            .into_iter()
+            .map(|args| f.call(args))
+            .collect()
+    }
+}
@@ -0,0 +1,102 @@
+use crate::tuple::zip_apply::{TupleZipApply, TupleZipApplyMut, TupleZipApplyOnce};
+
+/// Map each argument of the function with its own function.
+///
+/// Takes function `f: B1, B2, .. -> R` and tuple of functions
+/// `(g1: A1 -> B1, g2: A2 -> B2, ..)` and returns
+/// `|a1: A1, a2: A2, ..| f(g1(a1), g2(a2), ..)` (like [Ramda's `useWith`]).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::map_args;
+///
+/// let greet = |name: &str, times: usize, excited: bool| {
+///     format!("{}{}", name.repeat(times), if excited { "!" } else { "." })
+/// };
+/// let raw = map_args(greet, (str::trim, |s: &str| s.len(), |n: i32| n > 0));
+///
+/// assert_eq!(raw("  hi ", "xx", 1), "hihi!");
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::map_args2`] (and
+///   `map_args3`..`map_args6`)
+/// - extension on all functions: [`FnExt::map_args`]
+/// - [`TupleZipApply`]
+///
+/// [Ramda's `useWith`]: https://ramdajs.com/docs/#useWith
+/// [`fntools::map_args2`]: crate::map_args2
+/// [`FnExt::map_args`]: crate::unstable::FnExt::map_args
+/// [`TupleZipApply`]: crate::tuple::zip_apply::TupleZipApply
+#[inline]
+pub fn map_args<F, Gs>(f: F, gs: Gs) -> MapArgs<F, Gs> { MapArgs::new(f, gs) }
+
+/// Represents function `F` with arguments mapped by tuple of functions `Gs`.
+///
+/// For documentation see [`map_args`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct MapArgs<F, Gs> {
+    f: F,
+    gs: Gs,
+}
+
+impl<F, Gs> MapArgs<F, Gs> {
+    /// Creates version of the function `f` with arguments mapped by `gs`.
+    ///
+    /// It's preferred to use [`map_args`] instead.
+    #[inline]
+    pub fn new(f: F, gs: Gs) -> Self { MapArgs { f, gs } }
+
+    /// Returns inner function and tuple of argument mappers.
+    #[inline]
+    pub fn into_inner(self) -> (F, Gs) {
+        let MapArgs { f, gs } = self;
+        (f, gs)
+    }
+
+    /// Returns references to inner function and tuple of argument mappers.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &Gs) {
+        let MapArgs { f, gs } = self;
+        (f, gs)
+    }
+}
+
+impl<A, F, Gs> FnOnce<A> for MapArgs<F, Gs>
+where
+    Gs: TupleZipApplyOnce<A>,
+    F: FnOnce<Gs::Res>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let MapArgs { f, gs } = self;
+        f.call_once(gs.zip_apply_once(args))
+    }
+}
+
+impl<A, F, Gs> FnMut<A> for MapArgs<F, Gs>
+where
+    Gs: TupleZipApplyMut<A>,
+    F: FnMut<Gs::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let MapArgs { f, gs } = self;
+        f.call_mut(gs.zip_apply_mut(args))
+    }
+}
+
+impl<A, F, Gs> Fn<A> for MapArgs<F, Gs>
+where
+    Gs: TupleZipApply<A>,
+    F: Fn<Gs::Res>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let MapArgs { f, gs } = self;
+        f.call(gs.zip_apply(args))
+    }
+}
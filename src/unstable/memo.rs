@@ -0,0 +1,131 @@
+use std::hash::Hash;
+
+use crate::memo::{Cache, HashCache, Memoize, SyncMemoize};
+
+/// Memoize function of any number of arguments.
+///
+/// Returns [`Memoize`] which caches results of `f` in an unbounded
+/// [`HashCache`], keyed by the tuple of arguments. Unlike [stable version] the
+/// result can be called directly.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::memoize;
+/// use std::cell::Cell;
+///
+/// let calls = Cell::new(0);
+/// let add = memoize(|a: i32, b: i32| {
+///     calls.set(calls.get() + 1);
+///     a + b
+/// });
+///
+/// assert_eq!(add(1, 2), 3);
+/// assert_eq!(add(1, 2), 3);
+/// assert_eq!(add(2, 1), 3);
+/// assert_eq!(calls.get(), 2);
+///
+/// assert!(add.invalidate(&(1, 2)));
+/// assert_eq!(add.stats().hits, 1);
+/// ```
+///
+/// Other caches can be used via [`Memoize::with_cache`]:
+/// ```
+/// use fntools::memo::{LruCache, SyncMemoize};
+///
+/// let mul = SyncMemoize::with_cache(|a: u64, b: u64| a * b, LruCache::new(16));
+///
+/// assert_eq!(mul(6, 7), 42);
+/// assert_eq!(mul.stats().misses, 1);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::memoize`]
+/// - extension on all functions: [`FnExt::memoize`]
+///
+/// [stable version]: crate::memoize
+/// [`fntools::memoize`]: crate::memoize
+/// [`FnExt::memoize`]: crate::unstable::FnExt::memoize
+/// [`HashCache`]: crate::memo::HashCache
+#[inline]
+pub fn memoize<A, F>(f: F) -> Memoize<F, HashCache<A, F::Output>>
+where
+    F: Fn<A>,
+    A: Hash + Eq + Clone,
+    F::Output: Clone,
+{
+    Memoize::with_cache(f, HashCache::new())
+}
+
+impl<A, F, C> FnOnce<A> for Memoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, F, C> FnMut<A> for Memoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, F, C> Fn<A> for Memoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        self.get_or_call(args, |f, args| f.call(args))
+    }
+}
+
+impl<A, F, C> FnOnce<A> for SyncMemoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, F, C> FnMut<A> for SyncMemoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output { self.call(args) }
+}
+
+impl<A, F, C> Fn<A> for SyncMemoize<F, C>
+where
+    F: Fn<A>,
+    A: Clone,
+    F::Output: Clone,
+    C: Cache<Key = A, Value = F::Output>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        self.get_or_call(args, |f, args| f.call(args))
+    }
+}
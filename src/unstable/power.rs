@@ -0,0 +1,100 @@
+/// Apply function `n` times.
+///
+/// Takes function `f: T -> T` and returns `fⁿ = |x: T| f(f(..f(x)..))` (`f`
+/// applied `n` times). `f⁰` is identity.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::power;
+///
+/// let next_char = |c: char| (c as u8 + 1) as char;
+/// let rot3 = power(next_char, 3);
+///
+/// assert_eq!(rot3('a'), 'd');
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::iterate`]
+/// - extension on all functions: [`FnExt::power`]
+///
+/// [`fntools::iterate`]: crate::iterate
+/// [`FnExt::power`]: crate::unstable::FnExt::power
+#[inline]
+pub fn power<T, F>(f: F, n: usize) -> Power<F>
+where
+    F: FnMut<(T,), Output = T>,
+{
+    Power::new(f, n)
+}
+
+/// Represents function `F` applied `n` times.
+///
+/// For documentation see [`power`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Power<F> {
+    f: F,
+    n: usize,
+}
+
+impl<F> Power<F> {
+    /// Creates function which applies `f` `n` times.
+    ///
+    /// It's preferred to use [`power`] instead.
+    #[inline]
+    pub fn new<T>(f: F, n: usize) -> Self
+    where
+        F: FnMut<(T,), Output = T>,
+    {
+        Power { f, n }
+    }
+
+    /// Returns inner function and the number of applications.
+    #[inline]
+    pub fn into_inner(self) -> (F, usize) {
+        let Power { f, n } = self;
+        (f, n)
+    }
+
+    /// Returns reference to inner function and the number of applications.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, usize) {
+        let Power { f, n } = self;
+        (f, *n)
+    }
+}
+
+impl<T, F> FnOnce<(T,)> for Power<F>
+where
+    F: FnMut<(T,), Output = T>,
+{
+    type Output = T;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (x,): (T,)) -> Self::Output {
+        let Power { mut f, n } = self;
+        (0..n).fold(x, |x, _| f.call_mut((x,)))
+    }
+}
+
+impl<T, F> FnMut<(T,)> for Power<F>
+where
+    F: FnMut<(T,), Output = T>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output {
+        let Power { f, n } = self;
+        (0..*n).fold(x, |x, _| f.call_mut((x,)))
+    }
+}
+
+impl<T, F> Fn<(T,)> for Power<F>
+where
+    F: Fn<(T,), Output = T>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (x,): (T,)) -> Self::Output {
+        let Power { f, n } = self;
+        (0..*n).fold(x, |x, _| f.call((x,)))
+    }
+}
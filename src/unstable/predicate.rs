@@ -0,0 +1,450 @@
+use crate::predicate::Predicates;
+
+/// Logical and of two predicates (short-circuiting).
+///
+/// Takes predicates `p` and `q` and returns `|args..| p(args..) && q(args..)`.
+/// Predicates may have any number of arguments, as long as arguments are
+/// `Copy` (e.g. references).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::and;
+///
+/// let in_range = and(|x: &i32| *x >= 0, |x: &i32| *x < 10);
+///
+/// let v: Vec<_> = vec![-1, 0, 5, 10].into_iter().filter(in_range).collect();
+/// assert_eq!(v, [0, 5]);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::and`]
+/// - extension on all functions: [`FnExt::and`]
+///
+/// [`fntools::predicate::and`]: crate::predicate::and
+/// [`FnExt::and`]: crate::unstable::FnExt::and
+#[inline]
+pub fn and<P, Q>(p: P, q: Q) -> And<P, Q> { And::new(p, q) }
+
+/// Logical or of two predicates (short-circuiting).
+///
+/// Takes predicates `p` and `q` and returns `|args..| p(args..) || q(args..)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::or;
+///
+/// let same_parity = |a: &i32, b: &i32| a % 2 == b % 2;
+/// let ok = or(same_parity, |a: &i32, b: &i32| a == &0 || b == &0);
+///
+/// assert!(ok(&2, &4));
+/// assert!(ok(&0, &3));
+/// assert!(!ok(&1, &2));
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::or`]
+/// - extension on all functions: [`FnExt::or`]
+///
+/// [`fntools::predicate::or`]: crate::predicate::or
+/// [`FnExt::or`]: crate::unstable::FnExt::or
+#[inline]
+pub fn or<P, Q>(p: P, q: Q) -> Or<P, Q> { Or::new(p, q) }
+
+/// Logical exclusive or of two predicates.
+///
+/// Takes predicates `p` and `q` and returns `|args..| p(args..) ^ q(args..)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::xor;
+///
+/// let odd_or_negative = xor(|x: &i32| x % 2 != 0, |x: &i32| *x < 0);
+///
+/// let v: Vec<_> = (-3..=3).filter(odd_or_negative).collect();
+/// assert_eq!(v, [-2, 1, 3]);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::xor`]
+/// - extension on all functions: [`FnExt::xor`]
+///
+/// [`fntools::predicate::xor`]: crate::predicate::xor
+/// [`FnExt::xor`]: crate::unstable::FnExt::xor
+#[inline]
+pub fn xor<P, Q>(p: P, q: Q) -> Xor<P, Q> { Xor::new(p, q) }
+
+macro_rules! binary_predicate {
+    ($( #[$meta:meta] )* $name:ident, $fun:ident, $op:tt) => {
+        $( #[$meta] )*
+        #[must_use = "function combinators are lazy and do nothing unless called"]
+        #[derive(Debug, Clone, Copy)]
+        pub struct $name<P, Q> {
+            p: P,
+            q: Q,
+        }
+
+        impl<P, Q> $name<P, Q> {
+            #[doc = concat!("Creates `", stringify!($fun), "` of predicates `p` and `q`.")]
+            ///
+            #[doc = concat!("It's preferred to use [`", stringify!($fun), "`] instead.")]
+            #[inline]
+            pub fn new(p: P, q: Q) -> Self { $name { p, q } }
+
+            /// Returns inner predicates.
+            #[inline]
+            pub fn into_inner(self) -> (P, Q) {
+                let $name { p, q } = self;
+                (p, q)
+            }
+
+            /// Returns references to inner predicates.
+            #[inline]
+            pub fn as_inner(&self) -> (&P, &Q) {
+                let $name { p, q } = self;
+                (p, q)
+            }
+        }
+
+        impl<A, P, Q> FnOnce<A> for $name<P, Q>
+        where
+            A: Copy,
+            P: FnOnce<A, Output = bool>,
+            Q: FnOnce<A, Output = bool>,
+        {
+            type Output = bool;
+
+            #[inline]
+            extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+                let $name { p, q } = self;
+                p.call_once(args) $op q.call_once(args)
+            }
+        }
+
+        impl<A, P, Q> FnMut<A> for $name<P, Q>
+        where
+            A: Copy,
+            P: FnMut<A, Output = bool>,
+            Q: FnMut<A, Output = bool>,
+        {
+            #[inline]
+            extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+                let $name { p, q } = self;
+                p.call_mut(args) $op q.call_mut(args)
+            }
+        }
+
+        impl<A, P, Q> Fn<A> for $name<P, Q>
+        where
+            A: Copy,
+            P: Fn<A, Output = bool>,
+            Q: Fn<A, Output = bool>,
+        {
+            #[inline]
+            extern "rust-call" fn call(&self, args: A) -> Self::Output {
+                let $name { p, q } = self;
+                p.call(args) $op q.call(args)
+            }
+        }
+    };
+}
+
+binary_predicate!(
+    /// Represents logical and of predicates `P` and `Q`.
+    ///
+    /// For documentation see [`and`].
+    And, and, &&
+);
+
+binary_predicate!(
+    /// Represents logical or of predicates `P` and `Q`.
+    ///
+    /// For documentation see [`or`].
+    Or, or, ||
+);
+
+binary_predicate!(
+    /// Represents logical exclusive or of predicates `P` and `Q`.
+    ///
+    /// For documentation see [`xor`].
+    Xor, xor, ^
+);
+
+/// Logical negation of a predicate.
+///
+/// Takes predicate `p` and returns `|args..| !p(args..)`.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::not;
+///
+/// let v: Vec<_> = vec!["", "a", "", "b"]
+///     .into_iter()
+///     .filter(not(|s: &&str| s.is_empty()))
+///     .collect();
+/// assert_eq!(v, ["a", "b"]);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::not`]
+/// - extension on all functions: [`FnExt::not`]
+///
+/// [`fntools::predicate::not`]: crate::predicate::not
+/// [`FnExt::not`]: crate::unstable::FnExt::not
+#[inline]
+pub fn not<P>(p: P) -> Not<P> { Not::new(p) }
+
+/// Represents logical negation of predicate `P`.
+///
+/// For documentation see [`not`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct Not<P>(P);
+
+impl<P> Not<P> {
+    /// Creates negation of predicate `p`.
+    ///
+    /// It's preferred to use [`not`] instead.
+    #[inline]
+    pub fn new(p: P) -> Self { Not(p) }
+
+    /// Returns inner predicate.
+    #[inline]
+    pub fn into_inner(self) -> P {
+        let Not(p) = self;
+        p
+    }
+
+    /// Returns reference to inner predicate.
+    #[inline]
+    pub fn as_inner(&self) -> &P {
+        let Not(p) = self;
+        p
+    }
+}
+
+impl<A, P> FnOnce<A> for Not<P>
+where
+    P: FnOnce<A, Output = bool>,
+{
+    type Output = bool;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let Not(p) = self;
+        !p.call_once(args)
+    }
+}
+
+impl<A, P> FnMut<A> for Not<P>
+where
+    P: FnMut<A, Output = bool>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let Not(p) = self;
+        !p.call_mut(args)
+    }
+}
+
+impl<A, P> Fn<A> for Not<P>
+where
+    P: Fn<A, Output = bool>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let Not(p) = self;
+        !p.call(args)
+    }
+}
+
+/// Combine tuple of predicates into one which holds if **all** of them hold.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::all_of;
+///
+/// let small_even = all_of((|x: &u8| x % 2 == 0, |x: &u8| *x < 5));
+///
+/// let v: Vec<_> = (0..10).filter(small_even).collect();
+/// assert_eq!(v, [0, 2, 4]);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::all_of`]
+/// - [`Predicates`]
+///
+/// [`fntools::predicate::all_of`]: crate::predicate::all_of
+/// [`Predicates`]: crate::predicate::Predicates
+#[inline]
+pub fn all_of<T, Ps>(predicates: Ps) -> AllOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    AllOf::new(predicates)
+}
+
+/// Represents conjunction of tuple of predicates `Ps`.
+///
+/// For documentation see [`all_of`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct AllOf<Ps>(Ps);
+
+impl<Ps> AllOf<Ps> {
+    /// Creates conjunction of tuple of predicates.
+    ///
+    /// It's preferred to use [`all_of`] instead.
+    #[inline]
+    pub fn new<T>(predicates: Ps) -> Self
+    where
+        T: ?Sized,
+        Ps: Predicates<T>,
+    {
+        AllOf(predicates)
+    }
+
+    /// Returns inner predicates.
+    #[inline]
+    pub fn into_inner(self) -> Ps {
+        let AllOf(ps) = self;
+        ps
+    }
+
+    /// Returns reference to inner predicates.
+    #[inline]
+    pub fn as_inner(&self) -> &Ps {
+        let AllOf(ps) = self;
+        ps
+    }
+}
+
+impl<T, Ps> FnOnce<(&T,)> for AllOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    type Output = bool;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (value,): (&T,)) -> Self::Output { self.call((value,)) }
+}
+
+impl<T, Ps> FnMut<(&T,)> for AllOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (value,): (&T,)) -> Self::Output {
+        self.call((value,))
+    }
+}
+
+impl<T, Ps> Fn<(&T,)> for AllOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (value,): (&T,)) -> Self::Output {
+        let AllOf(ps) = self;
+        ps.all(value)
+    }
+}
+
+/// Combine tuple of predicates into one which holds if **any** of them holds.
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::any_of;
+///
+/// let blank = any_of((|s: &&str| s.is_empty(), |s: &&str| s.trim().is_empty()));
+///
+/// let v: Vec<_> = vec!["a", "", "  ", "b"].into_iter().filter(blank).collect();
+/// assert_eq!(v, ["", "  "]);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::predicate::any_of`]
+/// - [`Predicates`]
+///
+/// [`fntools::predicate::any_of`]: crate::predicate::any_of
+/// [`Predicates`]: crate::predicate::Predicates
+#[inline]
+pub fn any_of<T, Ps>(predicates: Ps) -> AnyOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    AnyOf::new(predicates)
+}
+
+/// Represents disjunction of tuple of predicates `Ps`.
+///
+/// For documentation see [`any_of`].
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct AnyOf<Ps>(Ps);
+
+impl<Ps> AnyOf<Ps> {
+    /// Creates disjunction of tuple of predicates.
+    ///
+    /// It's preferred to use [`any_of`] instead.
+    #[inline]
+    pub fn new<T>(predicates: Ps) -> Self
+    where
+        T: ?Sized,
+        Ps: Predicates<T>,
+    {
+        AnyOf(predicates)
+    }
+
+    /// Returns inner predicates.
+    #[inline]
+    pub fn into_inner(self) -> Ps {
+        let AnyOf(ps) = self;
+        ps
+    }
+
+    /// Returns reference to inner predicates.
+    #[inline]
+    pub fn as_inner(&self) -> &Ps {
+        let AnyOf(ps) = self;
+        ps
+    }
+}
+
+impl<T, Ps> FnOnce<(&T,)> for AnyOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    type Output = bool;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, (value,): (&T,)) -> Self::Output { self.call((value,)) }
+}
+
+impl<T, Ps> FnMut<(&T,)> for AnyOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, (value,): (&T,)) -> Self::Output {
+        self.call((value,))
+    }
+}
+
+impl<T, Ps> Fn<(&T,)> for AnyOf<Ps>
+where
+    T: ?Sized,
+    Ps: Predicates<T>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, (value,): (&T,)) -> Self::Output {
+        let AnyOf(ps) = self;
+        ps.any(value)
+    }
+}
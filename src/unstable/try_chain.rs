@@ -0,0 +1,134 @@
+use crate::fallible::{Fallible, FromResidual};
+
+/// Chain two fallible functions.
+///
+/// Takes functions `f` and `g` and returns something _like_
+/// `|a: A| g(f(a)?)`: if `f` returns `Err`/`None` then `g` isn't called and
+/// the error is returned (converted with `From` just like `?` does).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::try_chain;
+///
+/// let checked_add = |a: u8, b: u8| a.checked_add(b);
+/// let digit = |n: u8| std::char::from_digit(n.into(), 10);
+/// let fun = try_chain(checked_add, digit);
+///
+/// assert_eq!(fun(2, 3), Some('5'));
+/// assert_eq!(fun(7, 3), None);
+/// assert_eq!(fun(255, 1), None);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::try_chain`]
+/// - extension on all functions: [`FnExt::and_then`]
+///
+/// [`FnExt::and_then`]: crate::unstable::FnExt::and_then
+/// [`fntools::try_chain`]: crate::try_chain
+#[inline]
+pub fn try_chain<A, F, G>(f: F, g: G) -> TryChain<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: Fallible,
+    G: FnOnce<(<F::Output as Fallible>::Output,)>,
+    G::Output: FromResidual<<F::Output as Fallible>::Residual>,
+{
+    TryChain::new(f, g)
+}
+
+/// Represents fallible composition of 2 functions `G ∘ F`.
+///
+/// > Note: `TryChain` and [`TryCompose`] differ only in argument order.
+///
+/// For documentation see [`try_chain`].
+///
+/// [`TryCompose`]: crate::unstable::TryCompose
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct TryChain<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> TryChain<F, G> {
+    /// Creates fallible chain of functions `f` and `g`.
+    ///
+    /// It's preferred to use [`try_chain`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        F: FnOnce<A>,
+        F::Output: Fallible,
+        G: FnOnce<(<F::Output as Fallible>::Output,)>,
+        G::Output: FromResidual<<F::Output as Fallible>::Residual>,
+    {
+        TryChain { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let TryChain { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let TryChain { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for TryChain<F, G>
+where
+    F: FnOnce<A>,
+    F::Output: Fallible,
+    G: FnOnce<(<F::Output as Fallible>::Output,)>,
+    G::Output: FromResidual<<F::Output as Fallible>::Residual>,
+{
+    type Output = G::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let TryChain { f, g } = self;
+        match f.call_once(args).branch() {
+            Ok(b) => g(b),
+            Err(residual) => G::Output::from_residual(residual),
+        }
+    }
+}
+
+impl<A, F, G> FnMut<A> for TryChain<F, G>
+where
+    F: FnMut<A>,
+    F::Output: Fallible,
+    G: FnMut<(<F::Output as Fallible>::Output,)>,
+    G::Output: FromResidual<<F::Output as Fallible>::Residual>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let TryChain { f, g } = self;
+        match f.call_mut(args).branch() {
+            Ok(b) => g(b),
+            Err(residual) => G::Output::from_residual(residual),
+        }
+    }
+}
+
+impl<A, F, G> Fn<A> for TryChain<F, G>
+where
+    F: Fn<A>,
+    F::Output: Fallible,
+    G: Fn<(<F::Output as Fallible>::Output,)>,
+    G::Output: FromResidual<<F::Output as Fallible>::Residual>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let TryChain { f, g } = self;
+        match f.call(args).branch() {
+            Ok(b) => g(b),
+            Err(residual) => G::Output::from_residual(residual),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::fallible::{Fallible, FromResidual};
+
+/// Compose two fallible functions.
+///
+/// Takes functions `f` and `g` and returns something _like_
+/// `|a: A| f(g(a)?)`: if `g` returns `Err`/`None` then `f` isn't called and
+/// the error is returned (converted with `From` just like `?` does).
+///
+/// # Examples:
+/// ```
+/// use fntools::unstable::try_compose;
+///
+/// let checked_add = |a: u8, b: u8| a.checked_add(b);
+/// let digit = |n: u8| std::char::from_digit(n.into(), 10);
+/// let fun = try_compose(digit, checked_add);
+///
+/// assert_eq!(fun(2, 3), Some('5'));
+/// assert_eq!(fun(7, 3), None);
+/// assert_eq!(fun(255, 1), None);
+/// ```
+///
+/// See also:
+/// - stable version of this function: [`fntools::try_compose`]
+/// - chaining version of this function: [`try_chain`]
+///
+/// [`fntools::try_compose`]: crate::try_compose
+/// [`try_chain`]: crate::unstable::try_chain
+#[inline]
+pub fn try_compose<A, F, G>(f: F, g: G) -> TryCompose<F, G>
+where
+    G: FnOnce<A>,
+    G::Output: Fallible,
+    F: FnOnce<(<G::Output as Fallible>::Output,)>,
+    F::Output: FromResidual<<G::Output as Fallible>::Residual>,
+{
+    TryCompose::new(f, g)
+}
+
+/// Represents fallible composition of 2 functions `F ∘ G`.
+///
+/// > Note: `TryCompose` and [`TryChain`] differ only in argument order.
+///
+/// For documentation see [`try_compose`].
+///
+/// [`TryChain`]: crate::unstable::TryChain
+#[must_use = "function combinators are lazy and do nothing unless called"]
+#[derive(Debug, Clone, Copy)]
+pub struct TryCompose<F, G> {
+    f: F,
+    g: G,
+}
+
+impl<F, G> TryCompose<F, G> {
+    /// Creates fallible composition of functions `f` and `g`.
+    ///
+    /// It's preferred to use [`try_compose`] instead.
+    #[inline]
+    pub fn new<A>(f: F, g: G) -> Self
+    where
+        G: FnOnce<A>,
+        G::Output: Fallible,
+        F: FnOnce<(<G::Output as Fallible>::Output,)>,
+        F::Output: FromResidual<<G::Output as Fallible>::Residual>,
+    {
+        TryCompose { f, g }
+    }
+
+    /// Returns inner functions.
+    #[inline]
+    pub fn into_inner(self) -> (F, G) {
+        let TryCompose { f, g } = self;
+        (f, g)
+    }
+
+    /// Returns references to inner functions.
+    #[inline]
+    pub fn as_inner(&self) -> (&F, &G) {
+        let TryCompose { f, g } = self;
+        (f, g)
+    }
+}
+
+impl<A, F, G> FnOnce<A> for TryCompose<F, G>
+where
+    G: FnOnce<A>,
+    G::Output: Fallible,
+    F: FnOnce<(<G::Output as Fallible>::Output,)>,
+    F::Output: FromResidual<<G::Output as Fallible>::Residual>,
+{
+    type Output = F::Output;
+
+    #[inline]
+    extern "rust-call" fn call_once(self, args: A) -> Self::Output {
+        let TryCompose { f, g } = self;
+        match g.call_once(args).branch() {
+            Ok(b) => f(b),
+            Err(residual) => F::Output::from_residual(residual),
+        }
+    }
+}
+
+impl<A, F, G> FnMut<A> for TryCompose<F, G>
+where
+    G: FnMut<A>,
+    G::Output: Fallible,
+    F: FnMut<(<G::Output as Fallible>::Output,)>,
+    F::Output: FromResidual<<G::Output as Fallible>::Residual>,
+{
+    #[inline]
+    extern "rust-call" fn call_mut(&mut self, args: A) -> Self::Output {
+        let TryCompose { f, g } = self;
+        match g.call_mut(args).branch() {
+            Ok(b) => f(b),
+            Err(residual) => F::Output::from_residual(residual),
+        }
+    }
+}
+
+impl<A, F, G> Fn<A> for TryCompose<F, G>
+where
+    G: Fn<A>,
+    G::Output: Fallible,
+    F: Fn<(<G::Output as Fallible>::Output,)>,
+    F::Output: FromResidual<<G::Output as Fallible>::Residual>,
+{
+    #[inline]
+    extern "rust-call" fn call(&self, args: A) -> Self::Output {
+        let TryCompose { f, g } = self;
+        match g.call(args).branch() {
+            Ok(b) => f(b),
+            Err(residual) => F::Output::from_residual(residual),
+        }
+    }
+}
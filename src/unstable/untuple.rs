@@ -11,6 +11,24 @@
 /// let fun = untuple(Add::add);
 /// assert_eq!(fun(args), 3);
 /// ```
+///
+/// Making multi-argument step for [`trampoline`]:
+/// ```
+/// use fntools::{trampoline, unstable::untuple, Bounce};
+///
+/// let step = |n: u32, a: u64, b: u64| {
+///     if n == 0 {
+///         Bounce::Done(a)
+///     } else {
+///         Bounce::Continue((n - 1, b, a + b))
+///     }
+/// };
+/// let fib = trampoline(untuple(step));
+///
+/// assert_eq!(fib((10, 0, 1)), 55);
+/// ```
+///
+/// [`trampoline`]: crate::trampoline
 #[inline]
 pub fn untuple<A, F>(f: F) -> Untuple<F>
 where